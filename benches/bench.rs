@@ -1,40 +1,59 @@
 use adventofcode_2022::common::day_input_filename;
-use adventofcode_2022::common::get_file_lines;
+use adventofcode_2022::common::read_input;
 use adventofcode_2022::common::Solution;
+use adventofcode_2022::common::Solver;
 use adventofcode_2022::days;
 
 macro_rules! setup_benchmark {
     ($($day_name:ident),*) => {
         $(
             pub fn $day_name(c: &mut criterion::Criterion) {
-                let day_name = stringify!($day_name);
-                let day_num: u8 = day_name[3..].parse().unwrap();
-                c.bench_function(&format!("Day {}", day_num), |bencher| {
-                    let input_lines = get_file_lines(&day_input_filename(day_num)).unwrap();
-                    bencher.iter(|| days::$day_name::solve(&input_lines));
+                let name = stringify!($day_name);
+                let year: u16 = name[1..5].parse().unwrap();
+                let day_num: u8 = name[9..].parse().unwrap();
+                let solver = days::get_solver(year, day_num).unwrap();
+                c.bench_function(&format!("{} day {}", year, day_num), |bencher| {
+                    let input = read_input(&day_input_filename(year, day_num)).unwrap();
+                    bencher.iter(|| solver.solve(&input).unwrap());
+                });
+                c.bench_function(&format!("{} day {} parse", year, day_num), |bencher| {
+                    let input = read_input(&day_input_filename(year, day_num)).unwrap();
+                    bencher.iter(|| solver.parse(&input).unwrap());
+                });
+                c.bench_function(&format!("{} day {} A", year, day_num), |bencher| {
+                    let input_text = read_input(&day_input_filename(year, day_num)).unwrap();
+                    let input = solver.parse(&input_text).unwrap();
+                    bencher.iter(|| input.part_a());
+                });
+                c.bench_function(&format!("{} day {} B", year, day_num), |bencher| {
+                    let input_text = read_input(&day_input_filename(year, day_num)).unwrap();
+                    let input = solver.parse(&input_text).unwrap();
+                    bencher.iter(|| input.part_b());
                 });
             }
         )*
 
         pub fn days_all(c: &mut criterion::Criterion) {
-            let solvers_and_inputs: Vec<(fn(&[String]) -> Solution, Vec<String>)> = days::all_numbers()
-                .into_iter()
-                .map(|day| {
-                    (
-                        days::get_solver(day).unwrap(),
-                        get_file_lines(&day_input_filename(day)).unwrap(),
-                    )
-                })
-                .collect();
+            for year in days::all_years() {
+                let solvers_and_inputs: Vec<(&dyn Solver, String)> = days::all_numbers(year)
+                    .into_iter()
+                    .map(|day| {
+                        (
+                            days::get_solver(year, day).unwrap(),
+                            read_input(&day_input_filename(year, day)).unwrap(),
+                        )
+                    })
+                    .collect();
 
-            c.bench_function("All days", |bencher| {
-                bencher.iter(|| {
-                    solvers_and_inputs
-                        .iter()
-                        .map(|(solver, input)| solver(&input))
-                        .collect::<Vec<Solution>>()
-                })
-            });
+                c.bench_function(&format!("{} all days", year), |bencher| {
+                    bencher.iter(|| {
+                        solvers_and_inputs
+                            .iter()
+                            .map(|(solver, input)| solver.solve(input).unwrap())
+                            .collect::<Vec<Solution>>()
+                    })
+                });
+            }
         }
 
         criterion::criterion_group! {
@@ -50,7 +69,4 @@ macro_rules! setup_benchmark {
     };
 }
 
-setup_benchmark!(
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-);
+adventofcode_2022::for_each_day!(setup_benchmark);
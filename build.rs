@@ -0,0 +1,57 @@
+//! Registers every `src/days/<year>/dayNN.rs` module, so that adding a day only requires creating
+//! its file. This generates `$OUT_DIR/days.rs`, which is included by `src/days/mod.rs`.
+
+use std::fmt::Write;
+use std::path::Path;
+
+/// The names of the entries in `dir` that are `prefix` followed by `digits` ASCII digits and then
+/// `suffix`, in sorted order.
+fn numbered_entries(dir: &Path, prefix: &str, digits: usize, suffix: &str) -> Vec<String> {
+    let mut names: Vec<String> = std::fs::read_dir(dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.unwrap().file_name().into_string().ok()?;
+            let number = name.strip_prefix(prefix)?.strip_suffix(suffix)?;
+            (number.len() == digits && number.bytes().all(|b| b.is_ascii_digit()))
+                .then(|| name.strip_suffix(suffix).unwrap().to_string())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+fn main() {
+    let days_dir = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/days");
+    println!("cargo:rerun-if-changed={}", days_dir.display());
+
+    let mut out = String::new();
+    let mut registrations = Vec::new();
+    let mut test_names = Vec::new();
+    for year in numbered_entries(&days_dir, "", 4, "") {
+        let year_dir = days_dir.join(&year);
+        if !year_dir.is_dir() {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", year_dir.display());
+
+        writeln!(out, "pub mod y{year} {{").unwrap();
+        for module in numbered_entries(&year_dir, "day", 2, ".rs") {
+            let path = year_dir.join(format!("{module}.rs"));
+            writeln!(out, "    #[path = {:?}]", path.to_str().unwrap()).unwrap();
+            writeln!(out, "    pub mod {module};").unwrap();
+            registrations.push(format!("{year} => y{year}::{module}::D{}", &module[1..]));
+            test_names.push(format!("y{year}_{module}"));
+        }
+        writeln!(out, "}}").unwrap();
+    }
+    writeln!(out, "days!({});", registrations.join(", ")).unwrap();
+    writeln!(
+        out,
+        "/// Invoke `$m!(y2022_day01, y2022_day02, ...)` with a name for each day of each year.\n#[macro_export]\nmacro_rules! for_each_day {{ ($m:ident) => {{ $m!({}); }}; }}",
+        test_names.join(", ")
+    )
+    .unwrap();
+
+    let out_path = Path::new(&std::env::var("OUT_DIR").unwrap()).join("days.rs");
+    std::fs::write(out_path, out).unwrap();
+}
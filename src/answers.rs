@@ -0,0 +1,124 @@
+use std::fmt::Display;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::common::day_input_filename;
+use crate::common::read_input;
+use crate::error::Error;
+use crate::error::ParseError;
+use crate::util::parse;
+
+/// Known correct answers for one puzzle input.
+///
+/// Answers files use the same format as the text output of the runner: each answer starts on a
+/// line beginning with `A:` or `B:`, and any following lines without a label continue a
+/// multi-line answer. A part may be omitted if its answer is not known.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Answers {
+    pub a: Option<String>,
+    pub b: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Check {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+/// The answers file for an input file: `inputs/2022/day01.in` has answers in
+/// `inputs/2022/day01.ans`.
+/// Standard input has no answers file.
+pub fn answers_filename(input_path: &Path) -> Option<PathBuf> {
+    if input_path == Path::new("-") {
+        None
+    } else {
+        Some(input_path.with_extension("ans"))
+    }
+}
+
+/// The committed answers file for the author's own input of a day: `answers/2022/day01.ans`.
+pub fn day_answers_filename(year: u16, day: u8) -> PathBuf {
+    Path::new("answers")
+        .join(year.to_string())
+        .join(format!("day{day:02}.ans"))
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut parts: [Option<String>; 2] = [None, None];
+        let mut current = None;
+        for mut line in parse::lines(input) {
+            let label = ["A:", "B:"]
+                .iter()
+                .position(|label| line.starts_with(label));
+            match (label, current) {
+                (Some(part), _) => {
+                    if parts[part].is_some() {
+                        return Err(line.error("each part only once"));
+                    }
+                    line.until(":")?;
+                    line.accept(" ");
+                    parts[part] = Some(line.rest().to_string());
+                    current = Some(part);
+                }
+                (None, Some(part)) => {
+                    let answer = parts[part].as_mut().unwrap();
+                    answer.push('\n');
+                    answer.push_str(line.rest());
+                }
+                (None, None) => return Err(line.error(r#""A:" or "B:""#)),
+            }
+        }
+
+        let [a, b] =
+            parts.map(|answer| answer.map(|answer| answer.trim_end_matches('\n').to_string()));
+        Ok(Self { a, b })
+    }
+
+    /// Read the answers file at `path`, or return `None` if it does not exist.
+    pub fn read(path: &Path) -> Result<Option<Self>, Error> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let input = read_input(path)?;
+        Self::parse(&input)
+            .map(Some)
+            .map_err(|err| Error::AnswersFile(path.to_path_buf(), err))
+    }
+
+    /// Read the answers for `input_path` as an input for the given day: the answers file next to
+    /// the input if there is one, or else the committed answers if `input_path` is the day's
+    /// default input.
+    pub fn read_for_day(year: u16, day: u8, input_path: &Path) -> Result<Option<Self>, Error> {
+        let beside_input = match answers_filename(input_path) {
+            Some(path) => Self::read(&path)?,
+            None => None,
+        };
+        if beside_input.is_none() && input_path == day_input_filename(year, day) {
+            Self::read(&day_answers_filename(year, day))
+        } else {
+            Ok(beside_input)
+        }
+    }
+}
+
+impl Check {
+    pub fn new(expected: Option<&str>, answer: &str) -> Self {
+        match expected {
+            Some(expected) if expected == answer => Self::Pass,
+            Some(_) => Self::Fail,
+            None => Self::Unknown,
+        }
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "PASS"),
+            Self::Fail => write!(f, "FAIL"),
+            Self::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
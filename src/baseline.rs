@@ -0,0 +1,121 @@
+use std::fmt::Display;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::error::Error;
+use crate::error::ParseError;
+use crate::util::parse;
+use crate::util::parse::Line;
+
+/// Timings of one day over several runs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DayBench {
+    pub day: u8,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+/// Saved results of a benchmark run, to compare later runs against.
+///
+/// Baseline files have a line for the year and the number of iterations, followed by a line for
+/// each day:
+///
+/// ```text
+/// year: 2022
+/// iterations: 10
+/// day 1: median 0.123456 ms, p95 0.234567 ms
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Baseline {
+    pub year: u16,
+    pub iterations: usize,
+    pub days: Vec<DayBench>,
+}
+
+impl Baseline {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = parse::nonempty_lines(input);
+        let year = field(input, lines.next(), "year: ")?;
+        let iterations = field(input, lines.next(), "iterations: ")?;
+        let days = lines
+            .map(|mut line| {
+                line.expect("day ")?;
+                let day = line.number()?;
+                line.expect(": median ")?;
+                let median = time(&mut line)?;
+                line.expect(", p95 ")?;
+                let p95 = time(&mut line)?;
+                line.expect_end()?;
+                Ok(DayBench { day, median, p95 })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            year,
+            iterations,
+            days,
+        })
+    }
+
+    /// Read a baseline saved by [Baseline::write], which must have results for `year`.
+    pub fn read(path: &Path, year: u16) -> Result<Self, Error> {
+        let invalid = |message: String| Error::InvalidBaseline(path.to_path_buf(), message);
+        let contents = std::fs::read_to_string(path).map_err(|err| invalid(err.to_string()))?;
+        let baseline = Self::parse(&contents).map_err(|err| invalid(err.to_string()))?;
+        if baseline.year != year {
+            return Err(invalid(format!(
+                "results are for {}, not {year}",
+                baseline.year
+            )));
+        }
+        Ok(baseline)
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), Error> {
+        Ok(std::fs::write(path, self.to_string())?)
+    }
+
+    pub fn day(&self, day: u8) -> Option<&DayBench> {
+        self.days.iter().find(|result| result.day == day)
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ms = |duration: Duration| duration.as_secs_f64() * 1000.0;
+        writeln!(f, "year: {}", self.year)?;
+        writeln!(f, "iterations: {}", self.iterations)?;
+        for day in &self.days {
+            writeln!(
+                f,
+                "day {}: median {:.6} ms, p95 {:.6} ms",
+                day.day,
+                ms(day.median),
+                ms(day.p95)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The whole number on a line of the form `label: number`.
+fn field<T: std::str::FromStr>(
+    input: &str,
+    line: Option<Line>,
+    label: &str,
+) -> Result<T, ParseError> {
+    let mut line = line.ok_or_else(|| parse::end_of_input(input, format!("{label:?}")))?;
+    line.expect(label)?;
+    let value = line.number()?;
+    line.expect_end()?;
+    Ok(value)
+}
+
+/// Consume a time like `0.123456 ms`.
+fn time(line: &mut Line) -> Result<Duration, ParseError> {
+    let start = line.clone();
+    line.until(" ms")?
+        .parse()
+        .ok()
+        .and_then(|ms: f64| Duration::try_from_secs_f64(ms / 1000.0).ok())
+        .ok_or_else(|| start.error("time in milliseconds"))
+}
@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+use crate::timing::format_duration;
+use adventofcode_2022::baseline::Baseline;
+use adventofcode_2022::baseline::DayBench;
+
+pub fn print_header(baseline: bool) {
+    if baseline {
+        println!(
+            "{:>3}  {:>12}  {:>12}  {:>12}  {:>8}",
+            "Day", "Median", "p95", "Baseline", "Change"
+        );
+    } else {
+        println!("{:>3}  {:>12}  {:>12}", "Day", "Median", "p95");
+    }
+}
+
+/// Print the timings of one day, and their change from the baseline if any. Returns whether the
+/// median time is slower than the baseline by more than `threshold` percent.
+pub fn print_day(result: &DayBench, baseline: Option<&Baseline>, threshold: f64) -> bool {
+    let timings = format!(
+        "{:>3}  {:>12}  {:>12}",
+        result.day,
+        format_duration(result.median),
+        format_duration(result.p95)
+    );
+    match baseline.map(|baseline| baseline.day(result.day)) {
+        None => {
+            println!("{timings}");
+            false
+        }
+        Some(None) => {
+            println!("{timings}  {:>12}", "-");
+            false
+        }
+        Some(Some(before)) => {
+            let change = percent_change(before.median, result.median);
+            let regression = change > threshold;
+            println!(
+                "{timings}  {:>12}  {:>+7.1}%{}",
+                format_duration(before.median),
+                change,
+                if regression { "  REGRESSION" } else { "" }
+            );
+            regression
+        }
+    }
+}
+
+/// How much longer `after` is than `before`, in percent.
+fn percent_change(before: Duration, after: Duration) -> f64 {
+    if before.is_zero() {
+        0.0
+    } else {
+        (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0
+    }
+}
@@ -0,0 +1,43 @@
+use std::cell::RefCell;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+/// A flag to ask a running solution to stop early, for example because it took too long.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+/// Make `token` the cancellation token of the current thread, which [is_cancelled] checks.
+pub fn set_current(token: CancelToken) {
+    CURRENT.with(|current| *current.borrow_mut() = Some(token));
+}
+
+/// Whether the work on the current thread has been cancelled. Searches and other solutions that
+/// can run for a long time check this regularly, and return early with their best result so far
+/// once it is set. The result is then discarded by the runner.
+pub fn is_cancelled() -> bool {
+    CURRENT.with(|current| {
+        current
+            .borrow()
+            .as_ref()
+            .is_some_and(CancelToken::is_cancelled)
+    })
+}
@@ -1,25 +1,332 @@
+use std::borrow::Cow;
 use std::fs::File;
+use std::io::BufRead;
+use std::io::BufReader;
 use std::io::Read;
 use std::path::Path;
 use std::path::PathBuf;
 
+use crate::error::Error;
+use crate::error::ParseError;
+
 pub type Solution = (String, String);
 
-pub fn day_input_filename(day: u8) -> PathBuf {
-    let padded_day = format!("{day:02}");
-    Path::new("inputs").join(format!("day{padded_day}.in"))
+/// An example input from a puzzle description, with the answers given there. An answer is `None`
+/// if the description gives none for this example, or if the solution does not handle it.
+pub struct Example {
+    pub input: &'static str,
+    pub answer_a: Option<&'static str>,
+    pub answer_b: Option<&'static str>,
+    /// Parameter values that differ from the defaults for this example.
+    pub params: &'static [(&'static str, i64)],
+}
+
+impl Example {
+    pub const fn new(input: &'static str, answer_a: &'static str, answer_b: &'static str) -> Self {
+        Self {
+            input,
+            answer_a: Some(answer_a),
+            answer_b: Some(answer_b),
+            params: &[],
+        }
+    }
+
+    pub const fn with_params(self, params: &'static [(&'static str, i64)]) -> Self {
+        Self { params, ..self }
+    }
+}
+
+/// A puzzle constant that a solution takes as a parameter, such as a number of rounds, because
+/// the examples in the puzzle description use a different value than the puzzle input.
+pub struct Param {
+    pub name: &'static str,
+    pub default: i64,
+    pub description: &'static str,
+    /// The smallest value the solution can handle.
+    pub min: i64,
+    /// The largest value the solution can handle.
+    pub max: i64,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, default: i64, description: &'static str) -> Self {
+        Self {
+            name,
+            default,
+            description,
+            min: i64::MIN,
+            max: i64::MAX,
+        }
+    }
+
+    pub const fn with_range(self, min: i64, max: i64) -> Self {
+        Self { min, max, ..self }
+    }
+}
+
+/// Values for each of a day's [Param]s.
+#[derive(Clone, Debug)]
+pub struct Params {
+    values: Vec<(&'static str, i64)>,
+}
+
+impl Params {
+    /// The default values of `schema`.
+    pub fn new(schema: &'static [Param]) -> Self {
+        Self {
+            values: schema
+                .iter()
+                .map(|param| (param.name, param.default))
+                .collect(),
+        }
+    }
+
+    /// Set the parameter `name` if it exists, and return whether it does.
+    pub fn set(&mut self, name: &str, value: i64) -> bool {
+        match self.values.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, v)) => {
+                *v = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The value of the parameter `name`, which must be in the schema of the day.
+    ///
+    /// Panics if the value does not fit in `T`, which can't happen for values in the range of the
+    /// parameter if the range fits in `T`.
+    pub fn get<T: TryFrom<i64>>(&self, name: &str) -> T {
+        let value = self
+            .values
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, v)| *v)
+            .unwrap_or_else(|| panic!("Unknown parameter: {name}"));
+        T::try_from(value).unwrap_or_else(|_| panic!("Parameter {name} out of range: {value}"))
+    }
+}
+
+pub trait Day {
+    type Input<'a>;
+
+    const EXAMPLES: &'static [Example];
+    const PARAMS: &'static [Param] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part_a(input: &Self::Input<'_>, params: &Params) -> String;
+    fn part_b(input: &Self::Input<'_>, params: &Params) -> String;
+
+    /// Check that the parameter values can be used with `input`, so that [Day::part_a] and
+    /// [Day::part_b] don't need to handle values for which there is no answer.
+    fn check_params(_input: &Self::Input<'_>, _params: &Params) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Solve both parts in a single pass that reads `input` one line at a time, so that inputs
+    /// too large to hold in memory can be solved. Returns `None` if the solution needs the whole
+    /// input at once.
+    fn solve_stream(_input: &mut dyn BufRead, _params: &Params) -> Option<Result<Solution, Error>> {
+        None
+    }
+}
+
+pub trait Solver: Sync {
+    /// Parse `input`, to be solved with the given parameter values.
+    fn parse_with_params<'a>(
+        &self,
+        input: &'a str,
+        params: Params,
+    ) -> Result<Box<dyn ParsedInput + 'a>, ParseError>;
+    fn examples(&self) -> &'static [Example];
+    fn params(&self) -> &'static [Param];
+
+    /// See [Day::solve_stream].
+    fn solve_stream(
+        &self,
+        input: &mut dyn BufRead,
+        params: &Params,
+    ) -> Option<Result<Solution, Error>>;
+
+    /// Parse `input`, to be solved with the default parameter values.
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn ParsedInput + 'a>, ParseError> {
+        self.parse_with_params(input, Params::new(self.params()))
+    }
+
+    fn solve(&self, input: &str) -> Result<Solution, ParseError> {
+        let input = self.parse(input)?;
+        Ok((input.part_a(), input.part_b()))
+    }
+}
+
+pub trait ParsedInput {
+    fn part_a(&self) -> String;
+    fn part_b(&self) -> String;
+
+    /// See [Day::check_params].
+    fn check_params(&self) -> Result<(), Error>;
+}
+
+struct Parsed<'a, D: Day>(D::Input<'a>, Params);
+
+impl<'a, D: Day> ParsedInput for Parsed<'a, D> {
+    fn part_a(&self) -> String {
+        D::part_a(&self.0, &self.1)
+    }
+
+    fn part_b(&self) -> String {
+        D::part_b(&self.0, &self.1)
+    }
+
+    fn check_params(&self) -> Result<(), Error> {
+        D::check_params(&self.0, &self.1)
+    }
+}
+
+impl<D> Solver for D
+where
+    D: Day,
+    D: Sync,
+    D: 'static,
+{
+    fn parse_with_params<'a>(
+        &self,
+        input: &'a str,
+        params: Params,
+    ) -> Result<Box<dyn ParsedInput + 'a>, ParseError> {
+        Ok(Box::new(Parsed::<D>(D::parse(input)?, params)))
+    }
+
+    fn solve_stream(
+        &self,
+        input: &mut dyn BufRead,
+        params: &Params,
+    ) -> Option<Result<Solution, Error>> {
+        D::solve_stream(input, params)
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        D::EXAMPLES
+    }
+
+    fn params(&self) -> &'static [Param] {
+        D::PARAMS
+    }
 }
 
-pub fn get_file_lines(path: &Path) -> Result<Vec<String>, std::io::Error> {
+/// The default input file of a day, for example `inputs/2022/day01.in`. If there is no directory
+/// for the year, inputs are in `inputs` itself, for example `inputs/day01.in`, like in the inputs
+/// submodule.
+pub fn day_input_filename(year: u16, day: u8) -> PathBuf {
+    let filename = format!("day{day:02}.in");
+    let year_dir = Path::new("inputs").join(year.to_string());
+    if year_dir.is_dir() {
+        year_dir.join(filename)
+    } else {
+        Path::new("inputs").join(filename)
+    }
+}
+
+/// The input file of a day in the directory for its year, for example `inputs/2022/day01.in`,
+/// whether or not that directory exists.
+pub fn year_input_filename(year: u16, day: u8) -> PathBuf {
+    Path::new("inputs")
+        .join(year.to_string())
+        .join(format!("day{day:02}.in"))
+}
+
+/// Read the whole input file, or standard input if `path` is `-`.
+pub fn read_input(path: &Path) -> Result<String, Error> {
+    let mut contents = String::new();
+    open_input(path)?
+        .read_to_string(&mut contents)
+        .map_err(|err| Error::InputFile(path.to_path_buf(), err))?;
+    Ok(contents)
+}
+
+/// Open the input file, or standard input if `path` is `-`, for reading one line at a time.
+pub fn open_input(path: &Path) -> Result<Box<dyn BufRead>, Error> {
     if path == Path::new("-") {
-        read_lines(std::io::stdin())
+        Ok(Box::new(std::io::stdin().lock()))
     } else {
-        read_lines(File::open(path).expect("Input file not found: {path:?}"))
+        let file = File::open(path).map_err(|err| Error::InputFile(path.to_path_buf(), err))?;
+        Ok(Box::new(BufReader::new(file)))
     }
 }
 
-fn read_lines<I: Read>(mut source: I) -> Result<Vec<String>, std::io::Error> {
-    let mut contents: String = String::new();
-    source.read_to_string(&mut contents)?;
-    Ok(contents.lines().map(&str::to_string).collect())
+/// A change made by [normalize_input].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Normalization {
+    ByteOrderMark,
+    /// The number of lines that ended in CRLF.
+    CrLf(usize),
+    /// The number of lines that ended in spaces or tabs.
+    TrailingWhitespace(usize),
+    MissingFinalNewline,
+}
+
+impl std::fmt::Display for Normalization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ByteOrderMark => write!(f, "removed a UTF-8 byte order mark"),
+            Self::CrLf(count) => write!(f, "converted {count} CRLF line ending(s) to LF"),
+            Self::TrailingWhitespace(count) => {
+                write!(f, "removed trailing whitespace from {count} line(s)")
+            }
+            Self::MissingFinalNewline => write!(f, "added a missing newline at the end"),
+        }
+    }
+}
+
+/// Make `input` look like a clean Unix text file, as the solutions expect: remove a UTF-8 byte
+/// order mark, convert CRLF line endings to LF, remove spaces and tabs at the end of each line and
+/// add a missing newline at the end. Returns the changes made, if any.
+pub fn normalize_input(input: &str) -> (Cow<'_, str>, Vec<Normalization>) {
+    let mut changes = Vec::new();
+    let body = match input.strip_prefix('\u{feff}') {
+        Some(body) => {
+            changes.push(Normalization::ByteOrderMark);
+            body
+        }
+        None => input,
+    };
+
+    let mut normalized = String::with_capacity(body.len() + 1);
+    let (mut crlf, mut trailing_whitespace, mut missing_newline) = (0, 0, false);
+    for line in body.split_inclusive('\n') {
+        let line = match line.strip_suffix('\n') {
+            Some(line) => match line.strip_suffix('\r') {
+                Some(line) => {
+                    crlf += 1;
+                    line
+                }
+                None => line,
+            },
+            None => {
+                missing_newline = true;
+                line
+            }
+        };
+        let trimmed = line.trim_end_matches([' ', '\t']);
+        if trimmed.len() != line.len() {
+            trailing_whitespace += 1;
+        }
+        normalized.push_str(trimmed);
+        normalized.push('\n');
+    }
+    if crlf > 0 {
+        changes.push(Normalization::CrLf(crlf));
+    }
+    if trailing_whitespace > 0 {
+        changes.push(Normalization::TrailingWhitespace(trailing_whitespace));
+    }
+    if missing_newline {
+        changes.push(Normalization::MissingFinalNewline);
+    }
+
+    if changes.is_empty() {
+        (Cow::Borrowed(input), changes)
+    } else {
+        (Cow::Owned(normalized), changes)
+    }
 }
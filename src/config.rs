@@ -0,0 +1,87 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use adventofcode_2022::common::read_input;
+use adventofcode_2022::error::Error;
+use adventofcode_2022::error::ParseError;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings for talking to the Advent of Code website.
+///
+/// Settings are read from a config file with one `key = value` setting per line, and lines
+/// starting with `#` are ignored. Environment variables take precedence over the config file.
+#[derive(Debug)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+/// The config file used if none is given: `$XDG_CONFIG_HOME/adventofcode/config`, or
+/// `~/.config/adventofcode/config` if `XDG_CONFIG_HOME` is not set.
+pub fn default_config_path() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .map(|config_home| config_home.join("adventofcode").join("config"))
+}
+
+impl Config {
+    /// Read the config file at `path`, if it exists, and then apply overrides from the
+    /// environment variables `AOC_SESSION` (or `SESSION_COOKIE`) and `AOC_BASE_URL`.
+    pub fn load(path: Option<&Path>) -> Result<Self, Error> {
+        let mut config = Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+        };
+
+        if let Some(path) = path.filter(|path| path.exists()) {
+            let contents = read_input(path)?;
+            config
+                .read_settings(&contents)
+                .map_err(|err| Error::ConfigFile(path.to_path_buf(), err))?;
+        }
+
+        if let Some(session) = ["AOC_SESSION", "SESSION_COOKIE"]
+            .iter()
+            .find_map(|name| std::env::var(name).ok())
+        {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = std::env::var("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+
+        Ok(config)
+    }
+
+    fn read_settings(&mut self, contents: &str) -> Result<(), ParseError> {
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| ParseError::new(index + 1, 1, "key = value", Some(line)))?;
+            let value = value.trim().to_string();
+            match key.trim() {
+                "session" => self.session = Some(value),
+                "base_url" => self.base_url = value,
+                key => {
+                    return Err(ParseError::new(
+                        index + 1,
+                        1,
+                        "one of \"session\", \"base_url\"",
+                        Some(key),
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn session(&self) -> Result<&str, Error> {
+        self.session.as_deref().ok_or(Error::MissingSession)
+    }
+}
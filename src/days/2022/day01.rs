@@ -0,0 +1,89 @@
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::common::Solution;
+use crate::error::Error;
+use crate::error::ParseError;
+use crate::util::parse;
+
+fn solve_a(elves: &BinaryHeap<i32>) -> &i32 {
+    elves.peek().unwrap()
+}
+
+fn solve_b(mut elves: BinaryHeap<i32>) -> i32 {
+    elves.pop().unwrap() + elves.pop().unwrap() + elves.pop().unwrap()
+}
+
+pub struct Day01;
+
+impl Day for Day01 {
+    type Input<'a> = BinaryHeap<i32>;
+
+    const EXAMPLES: &'static [Example] = &[Example::new(
+        include_str!("examples/day01.in"),
+        "24000",
+        "45000",
+    )];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::lines(input)
+            .try_fold(vec![0], |mut elves, mut line| {
+                if line.is_empty() {
+                    elves.push(0);
+                } else {
+                    *elves.last_mut().unwrap() += line.parse_rest::<i32>("integer")?;
+                }
+                Ok(elves)
+            })
+            .and_then(|elves| {
+                if elves.len() < 3 {
+                    Err(parse::end_of_input(input, "at least 3 elves"))
+                } else {
+                    Ok(BinaryHeap::from(elves))
+                }
+            })
+    }
+
+    fn part_a(elves: &Self::Input<'_>, _: &Params) -> String {
+        solve_a(elves).to_string()
+    }
+
+    fn part_b(elves: &Self::Input<'_>, _: &Params) -> String {
+        solve_b(elves.clone()).to_string()
+    }
+
+    fn solve_stream(input: &mut dyn BufRead, _: &Params) -> Option<Result<Solution, Error>> {
+        // The three largest totals so far, largest first
+        let mut top = [0; 3];
+        let mut elves = 0;
+        let mut add_elf = |elf: i32| {
+            elves += 1;
+            if let Some(i) = top.iter().position(|t| elf > *t) {
+                top.copy_within(i..2, i + 1);
+                top[i] = elf;
+            }
+        };
+
+        let mut elf = 0;
+        let mut lines = 0;
+        let result = parse::for_each_line(input, |mut line| {
+            lines += 1;
+            if line.is_empty() {
+                add_elf(std::mem::take(&mut elf));
+            } else {
+                elf += line.parse_rest::<i32>("integer")?;
+            }
+            Ok(())
+        });
+        add_elf(elf);
+        if result.is_ok() && elves < 3 {
+            return Some(Err(
+                ParseError::new(lines + 1, 1, "at least 3 elves", None).into()
+            ));
+        }
+        Some(result.map(|()| (top[0].to_string(), top.iter().sum::<i32>().to_string())))
+    }
+}
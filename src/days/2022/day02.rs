@@ -0,0 +1,71 @@
+use std::io::BufRead;
+
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::common::Solution;
+use crate::error::Error;
+use crate::error::ParseError;
+use crate::util::parse;
+use crate::util::parse::Line;
+
+fn score_a((opp, r): (u8, u8)) -> u32 {
+    let my_move = r;
+    let result = (my_move + (3 - opp) + 1) % 3;
+    u32::from(my_move + 1 + result * 3)
+}
+
+fn score_b((opp, r): (u8, u8)) -> u32 {
+    let my_move = (r + opp + 2) % 3;
+    u32::from(my_move + 1 + r * 3)
+}
+
+fn solve_a(rounds: &[(u8, u8)]) -> u32 {
+    rounds.iter().copied().map(score_a).sum()
+}
+
+fn solve_b(rounds: &[(u8, u8)]) -> u32 {
+    rounds.iter().copied().map(score_b).sum()
+}
+
+fn parse_round(mut line: Line) -> Result<(u8, u8), ParseError> {
+    let opp = line.one_of("ABC")?;
+    line.expect(" ")?;
+    let r = line.one_of("XYZ")?;
+    line.expect_end()?;
+    Ok((opp as u8, r as u8))
+}
+
+pub struct Day02;
+
+impl Day for Day02 {
+    type Input<'a> = Vec<(u8, u8)>;
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new(include_str!("examples/day02.in"), "15", "12")];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::nonempty_lines(input).map(parse_round).collect()
+    }
+
+    fn part_a(rounds: &Self::Input<'_>, _: &Params) -> String {
+        solve_a(rounds).to_string()
+    }
+
+    fn part_b(rounds: &Self::Input<'_>, _: &Params) -> String {
+        solve_b(rounds).to_string()
+    }
+
+    fn solve_stream(input: &mut dyn BufRead, _: &Params) -> Option<Result<Solution, Error>> {
+        let (mut a, mut b) = (0, 0);
+        let result = parse::for_each_line(input, |line| {
+            if !line.is_empty() {
+                let round = parse_round(line)?;
+                a += score_a(round);
+                b += score_b(round);
+            }
+            Ok(())
+        });
+        Some(result.map(|()| (a.to_string(), b.to_string())))
+    }
+}
@@ -0,0 +1,64 @@
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::error::ParseError;
+use crate::util::parse;
+
+const ITEMS_BY_PRIORITY: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn flags_to_priority_sum(flags: u64) -> i32 {
+    (1..=52).filter(|i| flags & (1 << i) != 0).sum()
+}
+
+fn solve_a(rucksacks: &[(u64, u64)]) -> i32 {
+    rucksacks
+        .iter()
+        .map(|(l, r)| l & r)
+        .map(flags_to_priority_sum)
+        .sum()
+}
+
+fn solve_b(rucksacks: &[(u64, u64)]) -> i32 {
+    let mut sacks = rucksacks.iter();
+    let mut result = 0;
+    while let (Some((la, ra)), Some((lb, rb)), Some((lc, rc))) =
+        (sacks.next(), sacks.next(), sacks.next())
+    {
+        result += flags_to_priority_sum((la | ra) & (lb | rb) & (lc | rc));
+    }
+    result
+}
+
+pub struct Day03;
+
+impl Day for Day03 {
+    type Input<'a> = Vec<(u64, u64)>;
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new(include_str!("examples/day03.in"), "157", "70")];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::nonempty_lines(input)
+            .map(|mut line| {
+                let half = line.rest().len() / 2;
+                let mut compartments = (0, 0);
+                for _ in 0..half {
+                    compartments.0 |= 1 << (line.one_of(ITEMS_BY_PRIORITY)? + 1);
+                }
+                for _ in 0..half {
+                    compartments.1 |= 1 << (line.one_of(ITEMS_BY_PRIORITY)? + 1);
+                }
+                line.expect_end()?;
+                Ok(compartments)
+            })
+            .collect()
+    }
+
+    fn part_a(rucksacks: &Self::Input<'_>, _: &Params) -> String {
+        solve_a(rucksacks).to_string()
+    }
+
+    fn part_b(rucksacks: &Self::Input<'_>, _: &Params) -> String {
+        solve_b(rucksacks).to_string()
+    }
+}
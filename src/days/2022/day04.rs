@@ -0,0 +1,56 @@
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::error::ParseError;
+use crate::util::parse;
+
+type Interval = (usize, usize);
+
+fn solve_a(elves: &[(Interval, Interval)]) -> usize {
+    elves
+        .iter()
+        .filter(|((a_low, a_high), (b_low, b_high))| {
+            (a_low <= b_low && a_high >= b_high) || (b_low <= a_low && b_high >= a_high)
+        })
+        .count()
+}
+
+fn solve_b(elves: &[(Interval, Interval)]) -> usize {
+    elves
+        .iter()
+        .filter(|((a_low, a_high), (b_low, b_high))| a_high >= b_low && b_high >= a_low)
+        .count()
+}
+
+pub struct Day04;
+
+impl Day for Day04 {
+    type Input<'a> = Vec<(Interval, Interval)>;
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new(include_str!("examples/day04.in"), "2", "4")];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::nonempty_lines(input)
+            .map(|mut line| {
+                let a_low = line.number()?;
+                line.expect("-")?;
+                let a_high = line.number()?;
+                line.expect(",")?;
+                let b_low = line.number()?;
+                line.expect("-")?;
+                let b_high = line.number()?;
+                line.expect_end()?;
+                Ok(((a_low, a_high), (b_low, b_high)))
+            })
+            .collect()
+    }
+
+    fn part_a(elves: &Self::Input<'_>, _: &Params) -> String {
+        solve_a(elves).to_string()
+    }
+
+    fn part_b(elves: &Self::Input<'_>, _: &Params) -> String {
+        solve_b(elves).to_string()
+    }
+}
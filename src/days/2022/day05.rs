@@ -0,0 +1,117 @@
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::error::ParseError;
+use crate::util::parse;
+use crate::util::parse::Line;
+
+pub struct Instruction {
+    from: usize,
+    count: usize,
+    to: usize,
+}
+
+fn solve_a(mut stacks: Vec<Vec<char>>, program: &[Instruction]) -> String {
+    for inst in program {
+        for _ in 0..inst.count {
+            let moved = stacks[inst.from].pop().unwrap();
+            stacks[inst.to].push(moved);
+        }
+    }
+    stacks
+        .into_iter()
+        .map(|stack| *stack.last().unwrap())
+        .collect()
+}
+
+fn solve_b(mut stacks: Vec<Vec<char>>, program: &[Instruction]) -> String {
+    for inst in program {
+        let moved_first_idx = stacks[inst.from].len() - inst.count;
+        let moved: Vec<char> = stacks[inst.from].drain(moved_first_idx..).collect();
+        stacks[inst.to].extend(moved);
+    }
+    stacks
+        .into_iter()
+        .map(|stack| *stack.last().unwrap())
+        .collect()
+}
+
+fn stack_index(line: &mut Line, num_stacks: usize) -> Result<usize, ParseError> {
+    let start = line.clone();
+    match line.number::<usize>()? {
+        n @ 1.. if n <= num_stacks => Ok(n - 1),
+        _ => Err(start.error(format!("stack number between 1 and {num_stacks}"))),
+    }
+}
+
+pub struct Day05;
+
+impl Day for Day05 {
+    type Input<'a> = (Vec<Vec<char>>, Vec<Instruction>);
+
+    const EXAMPLES: &'static [Example] = &[Example::new(
+        include_str!("examples/day05.in"),
+        "CMZ",
+        "MCD",
+    )];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let (layers, program): (Vec<Vec<Option<char>>>, Vec<Instruction>) =
+            parse::nonempty_lines(input).try_fold(
+                (Vec::new(), Vec::with_capacity(input.lines().count())),
+                |(mut layers, mut instructions), mut line| {
+                    if line.starts_with(" 1") {
+                        // Stack labels
+                    } else if line.starts_with(" ") || line.starts_with("[") {
+                        let mut layer: Vec<Option<char>> = Vec::new();
+                        while !line.is_empty() {
+                            if line.accept("[") {
+                                layer.push(Some(line.char()?));
+                                line.expect("]")?;
+                            } else if line.accept("   ") {
+                                layer.push(None);
+                            } else {
+                                return Err(line.error(r#""[" or "   ""#));
+                            }
+                            if !line.accept(" ") {
+                                line.expect_end()?;
+                            }
+                        }
+                        layers.push(layer);
+                    } else {
+                        let num_stacks = layers.iter().map(Vec::len).max().unwrap_or(0);
+                        line.expect("move ")?;
+                        let count = line.number()?;
+                        line.expect(" from ")?;
+                        let from = stack_index(&mut line, num_stacks)?;
+                        line.expect(" to ")?;
+                        let to = stack_index(&mut line, num_stacks)?;
+                        line.expect_end()?;
+                        instructions.push(Instruction { count, from, to });
+                    }
+                    Ok((layers, instructions))
+                },
+            )?;
+
+        // Layers with empty stacks at the end may be shorter if trailing whitespace was removed
+        let layer_len = layers.iter().map(Vec::len).max().unwrap_or(0);
+        let mut stacks = vec![Vec::new(); layer_len];
+        for layer in layers.into_iter().rev() {
+            for (stack, name) in stacks.iter_mut().zip(layer) {
+                if let Some(n) = name {
+                    stack.push(n);
+                }
+            }
+        }
+
+        Ok((stacks, program))
+    }
+
+    fn part_a((stacks, program): &Self::Input<'_>, _: &Params) -> String {
+        solve_a(stacks.clone(), program)
+    }
+
+    fn part_b((stacks, program): &Self::Input<'_>, _: &Params) -> String {
+        solve_b(stacks.clone(), program)
+    }
+}
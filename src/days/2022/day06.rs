@@ -0,0 +1,136 @@
+use std::collections::VecDeque;
+use std::io::BufRead;
+use std::io::Read;
+
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::common::Solution;
+use crate::error::Error;
+use crate::error::ParseError;
+use crate::util::parse;
+
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
+
+/// Tracks the last `n` characters of the datastream, to find where they are all different.
+struct MarkerFinder {
+    n: usize,
+    recent: VecDeque<usize>,
+    counts: [u8; 26],
+    num_nonzero: usize,
+}
+
+impl MarkerFinder {
+    fn new(n: usize) -> Self {
+        Self {
+            n,
+            recent: VecDeque::with_capacity(n + 1),
+            counts: [0; 26],
+            num_nonzero: 0,
+        }
+    }
+
+    /// Add the next character, and return whether the last `n` characters are all different.
+    fn push(&mut self, c: usize) -> bool {
+        if self.counts[c] == 0 {
+            self.num_nonzero += 1;
+        }
+        self.counts[c] += 1;
+        self.recent.push_back(c);
+
+        if self.recent.len() > self.n {
+            let cin = self.recent.pop_front().unwrap();
+            self.counts[cin] -= 1;
+            if self.counts[cin] == 0 {
+                self.num_nonzero -= 1;
+            }
+        }
+        self.num_nonzero >= self.n
+    }
+}
+
+fn find_marker(chars: &[usize], n: usize) -> Option<usize> {
+    let mut finder = MarkerFinder::new(n);
+    chars.iter().position(|c| finder.push(*c)).map(|i| i + 1)
+}
+
+fn solve_b(chars: &[usize], n: usize) -> usize {
+    find_marker(chars, n).expect("parse checks that there is a marker")
+}
+
+pub struct Day06;
+
+impl Day for Day06 {
+    type Input<'a> = Vec<usize>;
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new(include_str!("examples/day06.in"), "7", "19")];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut line = parse::first_line(input)?;
+        let mut chars = Vec::with_capacity(line.rest().len());
+        while !line.is_empty() {
+            chars.push(line.one_of(ALPHABET)?);
+        }
+        // A marker of 14 different characters also ends with one of 4
+        if find_marker(&chars, 14).is_none() {
+            return Err(line.error("a marker of 14 different characters"));
+        }
+        Ok(chars)
+    }
+
+    fn part_a(chars: &Self::Input<'_>, _: &Params) -> String {
+        solve_b(chars, 4).to_string()
+    }
+
+    fn part_b(chars: &Self::Input<'_>, _: &Params) -> String {
+        solve_b(chars, 14).to_string()
+    }
+
+    fn solve_stream(input: &mut dyn BufRead, _: &Params) -> Option<Result<Solution, Error>> {
+        if let Err(err) = parse::skip_byte_order_mark(input) {
+            return Some(Err(err));
+        }
+        let mut finders = [(MarkerFinder::new(4), None), (MarkerFinder::new(14), None)];
+        let mut whitespace = false;
+        let mut len = 0;
+        for (i, byte) in input.bytes().enumerate() {
+            let byte = match byte {
+                Ok(b'\n' | b'\r') => break,
+                Ok(b' ' | b'\t') => {
+                    whitespace = true;
+                    continue;
+                }
+                Ok(byte) => byte,
+                Err(err) => return Some(Err(err.into())),
+            };
+            let error = |expected: String| {
+                let found = char::from(byte).to_string();
+                Some(Err(ParseError::new(1, i + 1, expected, Some(&found)).into()))
+            };
+            if whitespace {
+                return error("end of line".to_string());
+            }
+            let Some(c) = ALPHABET.bytes().position(|a| a == byte) else {
+                return error(format!("one of {ALPHABET:?}"));
+            };
+            for (finder, marker) in &mut finders {
+                if marker.is_none() && finder.push(c) {
+                    *marker = Some(i + 1);
+                }
+            }
+            len = i + 1;
+        }
+        // A marker of 14 different characters also ends with one of 4
+        match finders.map(|(_, marker)| marker) {
+            [Some(a), Some(b)] => Some(Ok((a.to_string(), b.to_string()))),
+            _ => Some(Err(ParseError::new(
+                1,
+                len + 1,
+                "a marker of 14 different characters",
+                None,
+            )
+            .into())),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::error::ParseError;
+use crate::util::parse;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Default)]
+pub struct FsDir<'a> {
+    dirs: BTreeMap<&'a str, FsDir<'a>>,
+    files_size: usize,
+}
+
+impl<'a> FsDir<'a> {
+    fn total_size(&self) -> usize {
+        self.files_size + self.dirs.values().map(FsDir::total_size).sum::<usize>()
+    }
+}
+
+fn solve_a(fs_tree: &FsDir) -> usize {
+    const SIZE_LIMIT: usize = 100000;
+    let size_here = fs_tree.total_size();
+    (if size_here <= SIZE_LIMIT {
+        size_here
+    } else {
+        0
+    }) + fs_tree.dirs.values().map(solve_a).sum::<usize>()
+}
+
+fn visit_all<'a, 'b: 'a>(
+    mut visited: Vec<&'b FsDir<'a>>,
+    current: &'b FsDir<'a>,
+) -> Vec<&'a FsDir<'a>> {
+    visited.push(current);
+    current.dirs.values().fold(visited, visit_all)
+}
+
+fn solve_b(fs_tree: &FsDir) -> usize {
+    const MAX_SIZE: usize = 70000000;
+    const TARGET_FREE_SIZE: usize = 30000000;
+    let size_here = fs_tree.total_size();
+
+    let delete_size = (size_here + TARGET_FREE_SIZE).saturating_sub(MAX_SIZE);
+
+    let candidate_dirs: Vec<&FsDir> = visit_all(Vec::new(), fs_tree);
+    candidate_dirs
+        .into_iter()
+        .map(FsDir::total_size)
+        .filter(|size| *size >= delete_size)
+        .min()
+        .unwrap()
+}
+
+pub struct Day07;
+
+impl Day for Day07 {
+    type Input<'a> = FsDir<'a>;
+
+    const EXAMPLES: &'static [Example] = &[Example::new(
+        include_str!("examples/day07.in"),
+        "95437",
+        "24933642",
+    )];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut fs_tree = FsDir::default();
+        let mut cwd_stack: Vec<&str> = vec![];
+
+        let mut lines = parse::nonempty_lines(input).peekable();
+        if lines.peek().is_none() {
+            return Err(parse::end_of_input(input, r#""$ cd " or "$ ls""#));
+        }
+        while let Some(mut line) = lines.next() {
+            if line.accept("$ cd ") {
+                match line.rest() {
+                    ".." => {
+                        cwd_stack.pop();
+                    }
+                    "/" => {
+                        cwd_stack.clear();
+                    }
+                    new_dir => {
+                        let cwd: &FsDir = cwd_stack.iter().fold(&fs_tree, |dir, cd| &dir.dirs[cd]);
+                        if !cwd.dirs.contains_key(new_dir) {
+                            return Err(line.error("name of a listed directory"));
+                        }
+                        cwd_stack.push(new_dir);
+                    }
+                };
+            } else if line.accept("$ ls") {
+                line.expect_end()?;
+                while let Some(mut line) = lines.next_if(|line| !line.starts_with("$")) {
+                    let cwd: &mut FsDir = cwd_stack
+                        .iter()
+                        .fold(&mut fs_tree, |mutref, cd| mutref.dirs.get_mut(cd).unwrap());
+
+                    if line.accept("dir ") {
+                        cwd.dirs.insert(line.rest(), FsDir::default());
+                    } else {
+                        cwd.files_size += line.number::<usize>()?;
+                        line.expect(" ")?;
+                    }
+                }
+            } else {
+                return Err(line.error(r#""$ cd " or "$ ls""#));
+            }
+        }
+        Ok(fs_tree)
+    }
+
+    fn part_a(fs_tree: &Self::Input<'_>, _: &Params) -> String {
+        solve_a(fs_tree).to_string()
+    }
+
+    fn part_b(fs_tree: &Self::Input<'_>, _: &Params) -> String {
+        solve_b(fs_tree).to_string()
+    }
+}
@@ -1,6 +1,10 @@
 use std::collections::HashSet;
 
-use crate::common::Solution;
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::error::ParseError;
+use crate::util::parse;
 
 fn count_visible<R: Clone + Iterator<Item = usize>>(
     map: &[Vec<u32>],
@@ -64,7 +68,8 @@ fn solve_a(map: &[Vec<u32>]) -> usize {
         (1..(w - 1)).rev(),
     );
 
-    visible.len() + 2 * w + 2 * h - 4
+    // Every tree on the edge is visible
+    visible.len() + w * h - w.saturating_sub(2) * h.saturating_sub(2)
 }
 
 fn solve_b(map: &[Vec<u32>]) -> usize {
@@ -78,10 +83,23 @@ fn solve_b(map: &[Vec<u32>]) -> usize {
         .unwrap()
 }
 
-pub fn solve(lines: &[String]) -> Solution {
-    let map: Vec<Vec<u32>> = lines
-        .iter()
-        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
-        .collect();
-    (solve_a(&map).to_string(), solve_b(&map).to_string())
+pub struct Day08;
+
+impl Day for Day08 {
+    type Input<'a> = Vec<Vec<u32>>;
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new(include_str!("examples/day08.in"), "21", "8")];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::grid(input, |line, _| Ok(line.one_of("0123456789")? as u32))
+    }
+
+    fn part_a(map: &Self::Input<'_>, _: &Params) -> String {
+        solve_a(map).to_string()
+    }
+
+    fn part_b(map: &Self::Input<'_>, _: &Params) -> String {
+        solve_b(map).to_string()
+    }
 }
@@ -0,0 +1,90 @@
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::error::ParseError;
+use crate::util::collections::GridCount;
+use crate::util::parse;
+
+fn simulate(moves: &[(i32, i32)], parts: usize) -> usize {
+    let mut pos: Vec<(i32, i32)> = vec![(0, 0); parts];
+    let mut visited: GridCount = GridCount::new();
+    visited.insert((0, 0));
+
+    for (dx, dy) in moves {
+        pos[0].0 += dx;
+        pos[0].1 += dy;
+
+        let mut any_changed = true;
+        while any_changed {
+            any_changed = false;
+            for i in 1..pos.len() {
+                let dhtx: i32 = pos[i - 1].0 - pos[i].0;
+                let dhty: i32 = pos[i - 1].1 - pos[i].1;
+
+                if dhtx.abs() >= 2 || dhty.abs() >= 2 {
+                    if dhtx.abs() + dhty.abs() >= 3 {
+                        pos[i].0 += dhtx.signum();
+                        pos[i].1 += dhty.signum();
+                    } else {
+                        if dhtx.abs() >= 2 {
+                            pos[i].0 += dhtx.signum();
+                        }
+                        if dhty.abs() >= 2 {
+                            pos[i].1 += dhty.signum();
+                        }
+                    }
+
+                    if i == pos.len() - 1 {
+                        visited.insert(pos[i]);
+                    }
+                    any_changed = true;
+                } else {
+                    break;
+                }
+            }
+        }
+    }
+
+    visited.len()
+}
+
+pub struct Day09;
+
+impl Day for Day09 {
+    type Input<'a> = Vec<(i32, i32)>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(include_str!("examples/day09.in"), "13", "1"),
+        Example {
+            input: include_str!("examples/day09-2.in"),
+            answer_a: None,
+            answer_b: Some("36"),
+            params: &[],
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::nonempty_lines(input)
+            .map(|mut line| {
+                let dir = line.one_of("UDLR")?;
+                line.expect(" ")?;
+                let r: i32 = line.number()?;
+                line.expect_end()?;
+                Ok(match dir {
+                    0 => (0, r),
+                    1 => (0, -r),
+                    2 => (-r, 0),
+                    _ => (r, 0),
+                })
+            })
+            .collect()
+    }
+
+    fn part_a(moves: &Self::Input<'_>, _: &Params) -> String {
+        simulate(moves, 2).to_string()
+    }
+
+    fn part_b(moves: &Self::Input<'_>, _: &Params) -> String {
+        simulate(moves, 10).to_string()
+    }
+}
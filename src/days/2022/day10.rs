@@ -0,0 +1,122 @@
+use std::io::BufRead;
+
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::common::Solution;
+use crate::error::Error;
+use crate::error::ParseError;
+use crate::util::parse;
+use crate::util::parse::Line;
+
+const TARGETS: [i32; 6] = [20, 60, 100, 140, 180, 220];
+const CRT_WIDTH: usize = 40;
+const CRT_HEIGHT: usize = 6;
+
+/// Which pixels of the CRT are lit. Cycles past the last pixel draw nothing.
+type Crt = [[bool; CRT_WIDTH]; CRT_HEIGHT];
+
+fn run_cycle(cycle: i32, x: i32, signal: i32, crt: &mut Crt, addx: Option<i32>) -> (i32, i32, i32) {
+    let crt_pos = (cycle - 1) as usize;
+    if let Some(row) = crt.get_mut(crt_pos / CRT_WIDTH) {
+        let column = crt_pos % CRT_WIDTH;
+        row[column] = (x - column as i32).abs() <= 1;
+    }
+
+    let new_signal = if TARGETS.contains(&cycle) {
+        signal + cycle * x
+    } else {
+        signal
+    };
+
+    (cycle + 1, x + addx.unwrap_or(0), new_signal)
+}
+
+/// Cycle, X register, sum of signal strengths and CRT pixels.
+type State = (i32, i32, i32, Crt);
+
+fn initial_state() -> State {
+    (1, 1, 0, [[false; CRT_WIDTH]; CRT_HEIGHT])
+}
+
+fn execute((cycle, x, signal, crt): &mut State, addx: Option<i32>) {
+    (*cycle, *x, *signal) = run_cycle(*cycle, *x, *signal, crt, None);
+    if addx.is_some() {
+        (*cycle, *x, *signal) = run_cycle(*cycle, *x, *signal, crt, addx);
+    }
+}
+
+/// The CRT output as text, with each row on a new line.
+fn render(crt: &Crt) -> String {
+    crt.iter()
+        .flat_map(|row| {
+            std::iter::once('\n').chain(row.iter().map(|lit| if *lit { '#' } else { '.' }))
+        })
+        .collect()
+}
+
+fn run(program: &[Option<i32>]) -> (i32, Crt) {
+    let mut state = initial_state();
+    for addx in program {
+        execute(&mut state, *addx);
+    }
+    let (_, _, signal, crt) = state;
+    (signal, crt)
+}
+
+fn parse_instruction(mut line: Line) -> Result<Option<i32>, ParseError> {
+    let addx = if line.accept("addx ") {
+        Some(line.number()?)
+    } else if line.accept("noop") {
+        None
+    } else {
+        return Err(line.error(r#""addx " or "noop""#));
+    };
+    line.expect_end()?;
+    Ok(addx)
+}
+
+pub struct Day10;
+
+impl Day for Day10 {
+    type Input<'a> = Vec<Option<i32>>;
+
+    const EXAMPLES: &'static [Example] = &[Example::new(
+        include_str!("examples/day10.in"),
+        "13140",
+        concat!(
+            "\n##..##..##..##..##..##..##..##..##..##..",
+            "\n###...###...###...###...###...###...###.",
+            "\n####....####....####....####....####....",
+            "\n#####.....#####.....#####.....#####.....",
+            "\n######......######......######......####",
+            "\n#######.......#######.......#######.....",
+        ),
+    )];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::nonempty_lines(input)
+            .map(parse_instruction)
+            .collect()
+    }
+
+    fn part_a(program: &Self::Input<'_>, _: &Params) -> String {
+        run(program).0.to_string()
+    }
+
+    fn part_b(program: &Self::Input<'_>, _: &Params) -> String {
+        render(&run(program).1)
+    }
+
+    fn solve_stream(input: &mut dyn BufRead, _: &Params) -> Option<Result<Solution, Error>> {
+        let mut state = initial_state();
+        let result = parse::for_each_line(input, |line| {
+            if !line.is_empty() {
+                execute(&mut state, parse_instruction(line)?);
+            }
+            Ok(())
+        });
+        let (_, _, signal, crt) = state;
+        Some(result.map(|()| (signal.to_string(), render(&crt))))
+    }
+}
@@ -0,0 +1,152 @@
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Param;
+use crate::common::Params;
+use crate::error::ParseError;
+use crate::util::parse;
+use std::collections::VecDeque;
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    items: VecDeque<u64>,
+    op: fn(u64, u64) -> u64,
+    op_arg: Option<u64>,
+    test_divisor: u64,
+    test_true_dest: usize,
+    test_false_dest: usize,
+}
+
+impl Monkey {
+    fn new() -> Self {
+        Self {
+            items: VecDeque::new(),
+            op: <u64 as std::ops::Add>::add,
+            op_arg: None,
+            test_divisor: 0,
+            test_true_dest: 0,
+            test_false_dest: 0,
+        }
+    }
+}
+
+fn solve_b(mut monkeys: Vec<Monkey>, rounds: usize, worry_decay: u64) -> usize {
+    let mut inspects = vec![0; monkeys.len()];
+    let all_divisors: u64 = monkeys.iter().map(|m| m.test_divisor).product();
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            while let Some(mut worry) = monkeys[i].items.pop_front() {
+                inspects[i] += 1;
+                worry = (monkeys[i].op)(worry, monkeys[i].op_arg.unwrap_or(worry));
+                worry /= worry_decay;
+                worry %= all_divisors;
+                let dest = if worry % monkeys[i].test_divisor == 0 {
+                    monkeys[i].test_true_dest
+                } else {
+                    monkeys[i].test_false_dest
+                };
+                monkeys[dest].items.push_back(worry);
+            }
+        }
+    }
+    inspects.sort();
+    inspects[monkeys.len() - 2] * inspects[monkeys.len() - 1]
+}
+
+pub struct Day11;
+
+impl Day for Day11 {
+    type Input<'a> = Vec<Monkey>;
+
+    const EXAMPLES: &'static [Example] = &[Example::new(
+        include_str!("examples/day11.in"),
+        "10605",
+        "2713310158",
+    )];
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("rounds_a", 20, "Number of rounds in part A").with_range(0, 10_000_000),
+        Param::new("rounds_b", 10000, "Number of rounds in part B").with_range(0, 10_000_000),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut monkeys = Vec::with_capacity(input.lines().count().div_ceil(7));
+
+        let mut dests = Vec::new();
+        let mut lines = parse::nonempty_lines(input);
+        while let Some(mut line) = lines.next() {
+            let header = line.clone();
+            line.expect("Monkey ")?;
+            let i_start = line.clone();
+            let i: usize = line.number()?;
+            if i > monkeys.len() {
+                return Err(i_start.error(format!("monkey number at most {}", monkeys.len())));
+            }
+            line.expect(":")?;
+            line.expect_end()?;
+
+            let mut monkey = Monkey::new();
+            for mut line in lines.by_ref().take(5) {
+                if line.accept("  Starting items: ") {
+                    while !line.is_empty() {
+                        monkey.items.push_back(line.number()?);
+                        if !line.accept(", ") {
+                            line.expect_end()?;
+                        }
+                    }
+                } else if line.accept("  Operation: new = old ") {
+                    monkey.op = match line.one_of("+*")? {
+                        0 => <u64 as std::ops::Add>::add,
+                        _ => <u64 as std::ops::Mul>::mul,
+                    };
+                    line.expect(" ")?;
+                    monkey.op_arg = if line.accept("old") {
+                        None
+                    } else {
+                        Some(line.number()?)
+                    };
+                } else if line.accept("  Test: divisible by ") {
+                    let divisor_start = line.clone();
+                    monkey.test_divisor = line.number()?;
+                    if monkey.test_divisor == 0 {
+                        return Err(divisor_start.error("nonzero divisor"));
+                    }
+                } else if line.accept("    If true: throw to monkey ") {
+                    dests.push((i, line.clone()));
+                    monkey.test_true_dest = line.number()?;
+                } else if line.accept("    If false: throw to monkey ") {
+                    dests.push((i, line.clone()));
+                    monkey.test_false_dest = line.number()?;
+                } else {
+                    return Err(line.error("monkey attribute"));
+                }
+                line.expect_end()?;
+            }
+            if monkey.test_divisor == 0 {
+                return Err(header.error(r#"monkey with a "Test: divisible by" line"#));
+            }
+            monkeys.insert(i, monkey);
+        }
+
+        if monkeys.len() < 2 {
+            return Err(parse::end_of_input(input, "at least 2 monkeys"));
+        }
+        for (i, dest_start) in dests {
+            let dest: usize = dest_start.clone().number()?;
+            if dest >= monkeys.len() || dest == i {
+                return Err(dest_start.error(format!(
+                    "number of another monkey less than {}",
+                    monkeys.len()
+                )));
+            }
+        }
+        Ok(monkeys)
+    }
+
+    fn part_a(monkeys: &Self::Input<'_>, params: &Params) -> String {
+        solve_b(monkeys.clone(), params.get("rounds_a"), 3).to_string()
+    }
+
+    fn part_b(monkeys: &Self::Input<'_>, params: &Params) -> String {
+        solve_b(monkeys.clone(), params.get("rounds_b"), 1).to_string()
+    }
+}
@@ -0,0 +1,77 @@
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::error::ParseError;
+use crate::search::bfs::bfs;
+use crate::util::parse;
+
+type Point = (usize, usize);
+const ASCII_A: u8 = 0x61;
+const ASCII_Z: u8 = 0x7a;
+const ELEVATIONS: &str = "abcdefghijklmnopqrstuvwxyz";
+
+fn search<'a, I>(starts: I, goal: Point, map: &[Vec<u8>]) -> usize
+where
+    I: IntoIterator<Item = &'a Point>,
+{
+    let neighbors = |&(r, c): &Point| {
+        [
+            (r.wrapping_sub(1), c),
+            (r + 1, c),
+            (r, c.wrapping_sub(1)),
+            (r, c + 1),
+        ]
+        .into_iter()
+        .filter(move |&(rr, cc)| {
+            rr < map.len() && cc < map[0].len() && map[rr][cc] <= map[r][c] + 1
+        })
+    };
+    *bfs(starts.into_iter().copied(), neighbors, |pos| *pos == goal)
+        .goal_distance()
+        .unwrap()
+}
+
+pub struct Day12;
+
+impl Day for Day12 {
+    type Input<'a> = (Point, Point, Vec<Point>, Vec<Vec<u8>>);
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new(include_str!("examples/day12.in"), "31", "29")];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut pos = None;
+        let mut goal = None;
+        let mut pos_b = vec![];
+        let map = parse::grid(input, |line, p| {
+            if pos.is_none() && line.accept("S") {
+                pos = Some(p);
+                Ok(ASCII_A)
+            } else if goal.is_none() && line.accept("E") {
+                goal = Some(p);
+                Ok(ASCII_Z)
+            } else {
+                let elevation = line.one_of(ELEVATIONS)?;
+                if elevation == 0 {
+                    pos_b.push(p);
+                }
+                Ok(ASCII_A + elevation as u8)
+            }
+        })?;
+
+        Ok((
+            pos.ok_or_else(|| parse::end_of_input(input, r#""S""#))?,
+            goal.ok_or_else(|| parse::end_of_input(input, r#""E""#))?,
+            pos_b,
+            map,
+        ))
+    }
+
+    fn part_a((pos, goal, _, map): &Self::Input<'_>, _: &Params) -> String {
+        search(Some(pos), *goal, map).to_string()
+    }
+
+    fn part_b((pos, goal, pos_b, map): &Self::Input<'_>, _: &Params) -> String {
+        search(pos_b.iter().chain(Some(pos)), *goal, map).to_string()
+    }
+}
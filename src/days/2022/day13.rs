@@ -0,0 +1,117 @@
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::error::ParseError;
+use crate::util::parse;
+use crate::util::parse::Line;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Packet {
+    Int(i32),
+    Packet(Vec<Packet>),
+}
+
+impl Packet {
+    fn parse(line: &mut Line) -> Result<Self, ParseError> {
+        if line.accept("[") {
+            let mut sub: Vec<Self> = Vec::new();
+            if !line.accept("]") {
+                loop {
+                    sub.push(Self::parse(line)?);
+                    if line.accept("]") {
+                        break;
+                    } else if !line.accept(",") {
+                        return Err(line.error(r#""," or "]""#));
+                    }
+                }
+            }
+            Ok(Self::Packet(sub))
+        } else {
+            Ok(Self::Int(line.number()?))
+        }
+    }
+
+    fn parse_line(mut line: Line) -> Result<Self, ParseError> {
+        let packet = Self::parse(&mut line)?;
+        line.expect_end()?;
+        Ok(packet)
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, rhs: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, rhs: &Self) -> std::cmp::Ordering {
+        match (self, rhs) {
+            (Self::Int(a), Self::Int(b)) => a.cmp(b),
+            (Self::Packet(va), Self::Int(b)) => va.cmp(&vec![Self::Int(*b)]),
+            (Self::Int(a), Self::Packet(vb)) => vec![Self::Int(*a)].cmp(vb),
+            (Self::Packet(va), Self::Packet(vb)) => va.cmp(vb),
+        }
+    }
+}
+
+fn solve_a(pairs: &[(Packet, Packet)]) -> usize {
+    pairs
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (i + 1, p))
+        .filter(|(_, (a, b))| a <= b)
+        .map(|(i, _)| i)
+        .sum()
+}
+
+fn solve_b(pairs: &[(Packet, Packet)]) -> usize {
+    let divider_1 = Packet::Packet(vec![Packet::Int(6)]);
+    let divider_2 = Packet::Packet(vec![Packet::Int(2)]);
+
+    let mut packets: Vec<Packet> = pairs
+        .iter()
+        .flat_map(|(a, b)| [a.clone(), b.clone()])
+        .collect();
+
+    packets.push(divider_1.clone());
+    packets.push(divider_2.clone());
+    packets.sort();
+
+    packets
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| **p == divider_1 || **p == divider_2)
+        .map(|(i, _)| i + 1)
+        .take(2)
+        .product()
+}
+
+pub struct Day13;
+
+impl Day for Day13 {
+    type Input<'a> = Vec<(Packet, Packet)>;
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new(include_str!("examples/day13.in"), "13", "140")];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut pairs: Vec<(Packet, Packet)> = Vec::with_capacity(input.lines().count() / 2);
+        let mut packet_lines = parse::nonempty_lines(input);
+        while let Some(line) = packet_lines.next() {
+            let second = packet_lines
+                .next()
+                .ok_or_else(|| parse::end_of_input(input, "packet"))?;
+            pairs.push((Packet::parse_line(line)?, Packet::parse_line(second)?));
+        }
+        Ok(pairs)
+    }
+
+    fn part_a(pairs: &Self::Input<'_>, _: &Params) -> String {
+        solve_a(pairs).to_string()
+    }
+
+    fn part_b(pairs: &Self::Input<'_>, _: &Params) -> String {
+        solve_b(pairs).to_string()
+    }
+}
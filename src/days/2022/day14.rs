@@ -1,5 +1,9 @@
-use crate::common::Solution;
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::error::ParseError;
 use crate::util::iter::WithSliding;
+use crate::util::parse;
 
 const SPAWN_X: usize = 500;
 const SPAWN_Y: usize = 0;
@@ -68,21 +72,29 @@ fn solve_b(map: Vec<Vec<bool>>, maxy: usize) -> usize {
     solve_a(map, maxy + 4, maxy + 2)
 }
 
-pub fn solve(lines: &[String]) -> Solution {
-    let (map, maxy) = lines.iter().filter(|line| !line.is_empty()).fold(
-        (Vec::new(), 0),
-        |(mut map, mut maxy), line| {
-            for ((startx, starty), (endx, endy)) in line
-                .split(" -> ")
-                .map(|s| {
-                    let mut halves = s.split(',');
-                    (
-                        halves.next().unwrap().parse().unwrap(),
-                        halves.next().unwrap().parse().unwrap(),
-                    )
-                })
-                .sliding2()
-            {
+pub struct Day14;
+
+impl Day for Day14 {
+    type Input<'a> = (Vec<Vec<bool>>, usize);
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new(include_str!("examples/day14.in"), "24", "93")];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::nonempty_lines(input).try_fold((Vec::new(), 0), |(mut map, mut maxy), mut line| {
+            let mut path: Vec<(usize, usize)> = Vec::new();
+            loop {
+                let x = line.number()?;
+                line.expect(",")?;
+                let y = line.number()?;
+                path.push((x, y));
+                if !line.accept(" -> ") {
+                    line.expect_end()?;
+                    break;
+                }
+            }
+
+            for ((startx, starty), (endx, endy)) in path.into_iter().sliding2() {
                 let lmaxy = std::cmp::max(starty, endy);
                 let lmaxx = std::cmp::max(startx, endx);
                 maxy = std::cmp::max(maxy, lmaxy);
@@ -107,12 +119,15 @@ pub fn solve(lines: &[String]) -> Solution {
                 }
             }
 
-            (map, maxy)
-        },
-    );
+            Ok((map, maxy))
+        })
+    }
 
-    (
-        solve_a(map.clone(), maxy + 1, maxy + 10).to_string(),
-        solve_b(map, maxy).to_string(),
-    )
+    fn part_a((map, maxy): &Self::Input<'_>, _: &Params) -> String {
+        solve_a(map.clone(), maxy + 1, maxy + 10).to_string()
+    }
+
+    fn part_b((map, maxy): &Self::Input<'_>, _: &Params) -> String {
+        solve_b(map.clone(), *maxy).to_string()
+    }
 }
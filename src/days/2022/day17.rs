@@ -1,7 +1,12 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
 
-use crate::common::Solution;
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Param;
+use crate::common::Params;
+use crate::error::ParseError;
+use crate::util::parse;
 
 type Point = (usize, usize);
 
@@ -35,7 +40,7 @@ const ROCKS: [Rock; 5] = [
     },
 ];
 
-fn solve_b(jet: &[bool], rocks_a: usize, rocks_b: usize) -> (usize, usize) {
+fn simulate(jet: &[bool], rocks: usize) -> usize {
     let mut num_settled: usize = 0;
     let mut settled: HashSet<Point> = HashSet::with_capacity(2022 * 5);
     let mut jet_i = 0;
@@ -44,13 +49,7 @@ fn solve_b(jet: &[bool], rocks_a: usize, rocks_b: usize) -> (usize, usize) {
     let mut states: HashMap<(usize, usize), Vec<(usize, usize)>> = HashMap::new();
     states.insert((0, 0), vec![(0, 0)]);
 
-    let mut sol_a = 0;
-
-    while num_settled < rocks_a * 10 {
-        if num_settled == rocks_a {
-            sol_a = h;
-        }
-
+    while num_settled < rocks {
         if let Some(st) = states.get(&(jet_i, rock_i)) {
             if st.len() > 2 {
                 let diffs: Vec<(usize, usize)> = st[1..]
@@ -60,9 +59,9 @@ fn solve_b(jet: &[bool], rocks_a: usize, rocks_b: usize) -> (usize, usize) {
                     .collect();
                 if diffs[1..].iter().all(|dh| *dh == diffs[0]) {
                     let (drock, dh) = diffs[0];
-                    if (rocks_b - num_settled) % drock == 0 {
-                        let n = (rocks_b - num_settled) / drock;
-                        return (sol_a, h + n * dh);
+                    if (rocks - num_settled).is_multiple_of(drock) {
+                        let n = (rocks - num_settled) / drock;
+                        return h + n * dh;
                     }
                 }
             }
@@ -135,7 +134,7 @@ fn solve_b(jet: &[bool], rocks_a: usize, rocks_b: usize) -> (usize, usize) {
 
         let new_h = std::cmp::max(h, 1 + points.iter().map(|(_, yy)| *yy).max().unwrap());
         h = new_h;
-        settled.extend(points.into_iter());
+        settled.extend(points);
         num_settled += 1;
         rock_i = (rock_i + 1) % ROCKS.len();
         states
@@ -144,21 +143,47 @@ fn solve_b(jet: &[bool], rocks_a: usize, rocks_b: usize) -> (usize, usize) {
             .push((num_settled, h));
     }
 
-    unimplemented!("Failed to find solution within 10 times part 1 solution")
+    h
 }
 
-pub fn solve(lines: &[String]) -> Solution {
-    let jet: Vec<bool> = lines[0]
-        .trim()
-        .chars()
-        .map(|c| match c {
-            '>' => true,
-            '<' => false,
-            _ => unimplemented!(),
-        })
-        .collect();
+pub struct Day17;
+
+impl Day for Day17 {
+    type Input<'a> = Vec<bool>;
+
+    const EXAMPLES: &'static [Example] = &[Example::new(
+        include_str!("examples/day17.in"),
+        "3068",
+        "1514285714288",
+    )];
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("rocks_a", 2022, "Number of rocks to drop in part A")
+            .with_range(0, i64::MAX / 4),
+        Param::new(
+            "rocks_b",
+            1000000000000,
+            "Number of rocks to drop in part B",
+        )
+        .with_range(0, i64::MAX / 4),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut line = parse::first_line(input)?;
+        let mut jet = Vec::with_capacity(line.rest().len());
+        loop {
+            jet.push(line.one_of("<>")? == 1);
+            if line.is_empty() {
+                break Ok(jet);
+            }
+        }
+    }
 
-    let (sol_a, sol_b) = solve_b(&jet, 2022, 1000000000000);
+    fn part_a(jet: &Self::Input<'_>, params: &Params) -> String {
+        simulate(jet, params.get("rocks_a")).to_string()
+    }
 
-    (sol_a.to_string(), sol_b.to_string())
+    fn part_b(jet: &Self::Input<'_>, params: &Params) -> String {
+        simulate(jet, params.get("rocks_b")).to_string()
+    }
 }
@@ -0,0 +1,143 @@
+use std::collections::HashSet;
+
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::error::ParseError;
+use crate::search::flood_fill::flood_fill;
+use crate::util::parse;
+
+type Point = (i16, i16, i16);
+
+const DXYZ: [Point; 6] = [
+    (-1, 0, 0),
+    (1, 0, 0),
+    (0, -1, 0),
+    (0, 1, 0),
+    (0, 0, -1),
+    (0, 0, 1),
+];
+
+fn solve_a(points: &HashSet<u64>, minx: i16, miny: i16, minz: i16) -> usize {
+    points
+        .iter()
+        .map(|h| unkey(*h, minx, miny, minz))
+        .map(|(x, y, z)| {
+            6 - DXYZ
+                .iter()
+                .map(|(dx, dy, dz)| (x + dx, y + dy, z + dz))
+                .filter(|p| points.contains(&tokey(*p, minx, miny, minz)))
+                .count()
+        })
+        .sum()
+}
+
+fn tokey((x, y, z): Point, minx: i16, miny: i16, minz: i16) -> u64 {
+    (((x - minx) as u64) << 32) | (((y - miny) as u64) << 16) | ((z - minz) as u64)
+}
+
+fn unkey(h: u64, minx: i16, miny: i16, minz: i16) -> Point {
+    let x = ((h >> 32) & 0xffff) as i16 + minx;
+    let y = ((h >> 16) & 0xffff) as i16 + miny;
+    let z = (h & 0xffff) as i16 + minz;
+    (x, y, z)
+}
+
+fn solve_b(
+    points: &HashSet<u64>,
+    minx: i16,
+    maxx: i16,
+    miny: i16,
+    maxy: i16,
+    minz: i16,
+    maxz: i16,
+) -> usize {
+    let lx = maxx - minx + 1;
+    let ly = maxy - miny + 1;
+    let lz = maxz - minz + 1;
+    let outer_surface = 2 * usize::try_from(lx * ly + lx * lz + ly * lz).unwrap();
+
+    let outer_points = flood_fill(Some(tokey((minx, miny, minz), minx, miny, minz)), |h| {
+        let (x, y, z) = unkey(*h, minx, miny, minz);
+        DXYZ.iter()
+            .map(move |(dx, dy, dz)| (x + dx, y + dy, z + dz))
+            .filter(move |&(nx, ny, nz)| {
+                nx >= minx && nx <= maxx && ny >= miny && ny <= maxy && nz >= minz && nz <= maxz
+            })
+            .map(move |next| tokey(next, minx, miny, minz))
+            .filter(|h| !points.contains(h))
+    });
+
+    outer_points
+        .iter()
+        .map(|h| {
+            let (x, y, z) = unkey(*h, minx, miny, minz);
+            6 - DXYZ
+                .iter()
+                .map(|(dx, dy, dz)| (x + dx, y + dy, z + dz))
+                .filter(|p| outer_points.contains(&tokey(*p, minx, miny, minz)))
+                .count()
+        })
+        .sum::<usize>()
+        - outer_surface
+}
+
+pub struct Droplet {
+    points: HashSet<u64>,
+    min: Point,
+    max: Point,
+}
+
+pub struct Day18;
+
+impl Day for Day18 {
+    type Input<'a> = Droplet;
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new(include_str!("examples/day18.in"), "64", "58")];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let points: Vec<Point> = parse::nonempty_lines(input)
+            .map(|mut line| {
+                let x = line.number()?;
+                line.expect(",")?;
+                let y = line.number()?;
+                line.expect(",")?;
+                let z = line.number()?;
+                line.expect_end()?;
+                Ok((x, y, z))
+            })
+            .collect::<Result<_, _>>()?;
+        if points.is_empty() {
+            return Err(parse::end_of_input(input, "point"));
+        }
+
+        let minx = points.iter().map(|(x, _, _)| x).min().unwrap() - 1;
+        let miny = points.iter().map(|(_, y, _)| y).min().unwrap() - 1;
+        let minz = points.iter().map(|(_, _, z)| z).min().unwrap() - 1;
+
+        let maxx = points.iter().map(|(x, _, _)| x).max().unwrap() + 1;
+        let maxy = points.iter().map(|(_, y, _)| y).max().unwrap() + 1;
+        let maxz = points.iter().map(|(_, _, z)| z).max().unwrap() + 1;
+
+        Ok(Droplet {
+            points: points
+                .into_iter()
+                .map(|p| tokey(p, minx, miny, minz))
+                .collect(),
+            min: (minx, miny, minz),
+            max: (maxx, maxy, maxz),
+        })
+    }
+
+    fn part_a(droplet: &Self::Input<'_>, _: &Params) -> String {
+        let (minx, miny, minz) = droplet.min;
+        solve_a(&droplet.points, minx, miny, minz).to_string()
+    }
+
+    fn part_b(droplet: &Self::Input<'_>, _: &Params) -> String {
+        let (minx, miny, minz) = droplet.min;
+        let (maxx, maxy, maxz) = droplet.max;
+        solve_b(&droplet.points, minx, maxx, miny, maxy, minz, maxz).to_string()
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::VecDeque;
+
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Param;
+use crate::common::Params;
+use crate::error::ParseError;
+use crate::util::parse;
+
+fn mix(nums: &[isize], times: usize) -> Vec<isize> {
+    let mut file: VecDeque<(usize, isize)> = nums.iter().copied().enumerate().collect();
+    let n = isize::try_from(file.len() - 1).unwrap();
+    for _ in 0..times {
+        for orig_i in 0..nums.len() {
+            let i = file
+                .iter()
+                .enumerate()
+                .find(|(_, (oi, _))| *oi == orig_i)
+                .map(|(i, _)| i)
+                .unwrap();
+            let (orig_i, v) = file.remove(i).unwrap();
+            let new_i: usize =
+                usize::try_from((isize::try_from(i).unwrap() + v).rem_euclid(n)).unwrap();
+            file.insert(new_i, (orig_i, v))
+        }
+    }
+
+    file.into_iter().map(|(_, v)| v).collect()
+}
+
+fn solve_b(nums: &[isize], key: isize, times: usize) -> isize {
+    mix(&nums.iter().map(|i| i * key).collect::<Vec<isize>>(), times)
+        .into_iter()
+        .cycle()
+        .skip_while(|i| *i != 0)
+        .step_by(1000)
+        .skip(1)
+        .take(3)
+        .sum()
+}
+
+pub struct Day20;
+
+impl Day for Day20 {
+    type Input<'a> = Vec<isize>;
+
+    const EXAMPLES: &'static [Example] = &[Example::new(
+        include_str!("examples/day20.in"),
+        "3",
+        "1623178306",
+    )];
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("key", 811589153, "Decryption key in part B")
+            .with_range(i32::MIN as i64, i32::MAX as i64),
+        Param::new("rounds_b", 10, "Number of times to mix the file in part B")
+            .with_range(0, u32::MAX as i64),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let nums: Vec<isize> = parse::nonempty_lines(input)
+            .map(|mut line| line.parse_rest("integer"))
+            .collect::<Result<_, _>>()?;
+        if nums.len() < 2 {
+            return Err(parse::end_of_input(input, "at least 2 numbers"));
+        }
+        if !nums.contains(&0) {
+            return Err(parse::end_of_input(input, "a number 0"));
+        }
+        Ok(nums)
+    }
+
+    fn part_a(nums: &Self::Input<'_>, _: &Params) -> String {
+        solve_b(nums, 1, 1).to_string()
+    }
+
+    fn part_b(nums: &Self::Input<'_>, params: &Params) -> String {
+        solve_b(nums, params.get("key"), params.get("rounds_b")).to_string()
+    }
+}
@@ -1,16 +1,20 @@
 use std::collections::HashMap;
 
-use crate::common::Solution;
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::error::ParseError;
+use crate::util::parse;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
-enum Operation {
+pub enum Operation {
     Add,
     Sub,
     Mul,
     Div,
 }
 
-enum Instruction<'a> {
+pub enum Instruction<'a> {
     Num(i64),
     Op(&'a str, Operation, &'a str),
 }
@@ -146,35 +150,67 @@ fn solve_b(monkeys: &HashMap<&str, Instruction>) -> i64 {
     }
 }
 
-pub fn solve(lines: &[String]) -> Solution {
-    let monkeys: HashMap<&str, Instruction> = lines
-        .iter()
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            let mut parts = line.split(": ");
-            let name = parts.next().unwrap();
-            let rhs = parts.next().unwrap();
-            (
-                name,
-                if let Ok(n) = rhs.parse() {
-                    Instruction::Num(n)
+pub struct Day21;
+
+impl Day for Day21 {
+    type Input<'a> = HashMap<&'a str, Instruction<'a>>;
+
+    const EXAMPLES: &'static [Example] = &[Example::new(
+        include_str!("examples/day21.in"),
+        "152",
+        "301",
+    )];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut operands = Vec::new();
+        let monkeys: HashMap<&str, Instruction> = parse::nonempty_lines(input)
+            .map(|mut line| {
+                let name = line.until(": ")?;
+                let instruction = if line.rest().starts_with(|c: char| c.is_ascii_digit()) {
+                    Instruction::Num(line.parse_rest("integer")?)
                 } else {
-                    let mut expr = rhs.split(' ');
-                    Instruction::Op(
-                        expr.next().unwrap(),
-                        match expr.next().unwrap() {
-                            "+" => Operation::Add,
-                            "-" => Operation::Sub,
-                            "*" => Operation::Mul,
-                            "/" => Operation::Div,
-                            _ => unimplemented!(),
-                        },
-                        expr.next().unwrap(),
-                    )
-                },
-            )
-        })
-        .collect();
+                    operands.push(line.clone());
+                    let a = line.until(" ")?;
+                    let op = match line.one_of("+-*/")? {
+                        0 => Operation::Add,
+                        1 => Operation::Sub,
+                        2 => Operation::Mul,
+                        _ => Operation::Div,
+                    };
+                    line.expect(" ")?;
+                    operands.push(line.clone());
+                    let b = line.rest();
+                    if b.is_empty() {
+                        return Err(line.error("name of a monkey"));
+                    }
+                    Instruction::Op(a, op, b)
+                };
+                Ok((name, instruction))
+            })
+            .collect::<Result<_, _>>()?;
+
+        for operand in operands {
+            if !monkeys.contains_key(operand.clone().until_or_end(" ")) {
+                return Err(operand.error("name of a monkey"));
+            }
+        }
+        if !matches!(monkeys.get("root"), Some(Instruction::Op(..))) {
+            return Err(parse::end_of_input(
+                input,
+                "a monkey named root with an operation",
+            ));
+        }
+        if !monkeys.contains_key("humn") {
+            return Err(parse::end_of_input(input, "a monkey named humn"));
+        }
+        Ok(monkeys)
+    }
 
-    (solve_a(&monkeys).to_string(), solve_b(&monkeys).to_string())
+    fn part_a(monkeys: &Self::Input<'_>, _: &Params) -> String {
+        solve_a(monkeys).to_string()
+    }
+
+    fn part_b(monkeys: &Self::Input<'_>, _: &Params) -> String {
+        solve_b(monkeys).to_string()
+    }
 }
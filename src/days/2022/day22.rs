@@ -0,0 +1,396 @@
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::error::ParseError;
+use crate::util::parse;
+
+struct Connections {
+    from: (usize, usize),
+    up: Connection,
+    down: Connection,
+    left: Connection,
+    right: Connection,
+}
+
+struct Connection {
+    to: (usize, usize),
+    rot: usize,
+}
+
+type Vector = [i8; 3];
+
+fn neg(v: Vector) -> Vector {
+    v.map(|x| -x)
+}
+
+/// How a face of the net lies on the folded cube: its outward normal, and the directions of
+/// moving right and down on it.
+#[derive(Clone, Copy)]
+struct Orientation {
+    normal: Vector,
+    right: Vector,
+    down: Vector,
+}
+
+impl Orientation {
+    /// The direction `dir` on this face.
+    fn direction(&self, dir: usize) -> Vector {
+        match dir {
+            0 => self.right,
+            1 => self.down,
+            2 => neg(self.right),
+            3 => neg(self.down),
+            _ => unimplemented!(),
+        }
+    }
+
+    /// The orientation of the face next to this one in the net in direction `dir`, once the net
+    /// is folded along the edge between them.
+    fn fold(&self, dir: usize) -> Self {
+        let Self {
+            normal,
+            right,
+            down,
+        } = *self;
+        match dir {
+            0 => Self {
+                normal: right,
+                right: neg(normal),
+                down,
+            },
+            1 => Self {
+                normal: down,
+                right,
+                down: neg(normal),
+            },
+            2 => Self {
+                normal: neg(right),
+                right: normal,
+                down,
+            },
+            3 => Self {
+                normal: neg(down),
+                right,
+                down: normal,
+            },
+            _ => unimplemented!(),
+        }
+    }
+}
+
+/// Fold the map into a cube, and find where each edge of each face leads. Returns `None` if the
+/// map is not the net of a cube with sides of `cube_side` tiles.
+fn fold_cube(map: &Map, cube_side: usize) -> Option<Vec<Connections>> {
+    if !map.walls.len().is_multiple_of(cube_side) {
+        return None;
+    }
+    let mut faces = Vec::new();
+    for fy in 0..map.walls.len() / cube_side {
+        let r = fy * cube_side;
+        if !map.minic[r].is_multiple_of(cube_side)
+            || !map.maxxc[r].is_multiple_of(cube_side)
+            || (r..r + cube_side)
+                .any(|r2| map.minic[r2] != map.minic[r] || map.maxxc[r2] != map.maxxc[r])
+        {
+            return None;
+        }
+        faces.extend((map.minic[r] / cube_side..map.maxxc[r] / cube_side).map(|fx| (fx, fy)));
+    }
+    if faces.len() != 6 {
+        return None;
+    }
+
+    let mut orientations: Vec<Option<Orientation>> = vec![None; 6];
+    orientations[0] = Some(Orientation {
+        normal: [0, 0, -1],
+        right: [1, 0, 0],
+        down: [0, 1, 0],
+    });
+    let mut queue = vec![0];
+    while let Some(i) = queue.pop() {
+        let (fx, fy) = faces[i];
+        let orientation = orientations[i]?;
+        let neighbors = [
+            Some((fx + 1, fy)),
+            Some((fx, fy + 1)),
+            fx.checked_sub(1).map(|fx| (fx, fy)),
+            fy.checked_sub(1).map(|fy| (fx, fy)),
+        ];
+        for (dir, neighbor) in neighbors.into_iter().enumerate() {
+            if let Some(j) = neighbor.and_then(|pos| faces.iter().position(|face| *face == pos)) {
+                if orientations[j].is_none() {
+                    orientations[j] = Some(orientation.fold(dir));
+                    queue.push(j);
+                }
+            }
+        }
+    }
+    let orientations: Vec<Orientation> = orientations.into_iter().collect::<Option<_>>()?;
+
+    let connect = |orientation: &Orientation, dir: usize| -> Option<Connection> {
+        let to = orientations
+            .iter()
+            .position(|other| other.normal == orientation.direction(dir))?;
+        let ndir =
+            (0..4).find(|ndir| orientations[to].direction(*ndir) == neg(orientation.normal))?;
+        Some(Connection {
+            to: faces[to],
+            rot: (ndir + 4 - dir) % 4,
+        })
+    };
+    faces
+        .iter()
+        .zip(&orientations)
+        .map(|(from, orientation)| {
+            Some(Connections {
+                from: *from,
+                right: connect(orientation, 0)?,
+                down: connect(orientation, 1)?,
+                left: connect(orientation, 2)?,
+                up: connect(orientation, 3)?,
+            })
+        })
+        .collect()
+}
+
+fn rot((x, y): (isize, isize), r: usize, cube_side: usize) -> (isize, isize) {
+    if r >= 1 {
+        rot(((cube_side - 1) as isize - y, x), r - 1, cube_side)
+    } else {
+        (x, y)
+    }
+}
+
+pub struct Map {
+    minic: Vec<usize>,
+    maxxc: Vec<usize>,
+    minir: Vec<usize>,
+    maxxr: Vec<usize>,
+    walls: Vec<Vec<bool>>,
+    cube_side: usize,
+    cube: Vec<Connections>,
+}
+
+impl Map {
+    fn new(h: usize) -> Self {
+        Self {
+            minic: Vec::with_capacity(h),
+            maxxc: Vec::with_capacity(h),
+            minir: Vec::with_capacity(h),
+            maxxr: Vec::with_capacity(h),
+            walls: Vec::with_capacity(h),
+            cube_side: 0,
+            cube: Vec::new(),
+        }
+    }
+}
+
+fn solve_a(map: &Map, path_len: &[usize], path_turn: &[bool]) -> usize {
+    let mut c = map.minic[0];
+    let mut r = map.minir[c];
+    let mut dir = 0;
+
+    for (i, l) in path_len.iter().copied().enumerate() {
+        let h = map.maxxr[c] - map.minir[c];
+        let w = map.maxxc[r] - map.minic[r];
+
+        let (dr, dc) = match dir {
+            0 => (0, 1),
+            1 => (1, 0),
+            2 => (0, w - 1),
+            3 => (h - 1, 0),
+            _ => unimplemented!(),
+        };
+
+        let dx = (1..=l)
+            .take_while(|dx| {
+                let nr = map.minir[c] + (r - map.minir[c] + dr * dx) % h;
+                let nc = map.minic[r] + (c - map.minic[r] + dc * dx) % w;
+                !map.walls[nr][nc]
+            })
+            .last()
+            .unwrap_or(0);
+
+        r = if dr == 0 {
+            r
+        } else {
+            map.minir[c] + (r - map.minir[c] + dr * dx) % h
+        };
+        c = if dc == 0 {
+            c
+        } else {
+            map.minic[r] + (c - map.minic[r] + dc * dx) % w
+        };
+
+        dir = match path_turn.get(i) {
+            Some(true) => (dir + 1) % 4,
+            Some(false) => (dir + 3) % 4,
+            None => dir,
+        }
+    }
+
+    (r + 1) * 1000 + (c + 1) * 4 + dir
+}
+
+fn solve_b(map: &Map, path_len: &[usize], path_turn: &[bool]) -> usize {
+    let cube_side = map.cube_side;
+    let mut c = map.minic[0];
+    let mut r = map.minir[c];
+    let mut dir = 0;
+
+    let mut poss = vec![];
+
+    for (i, l) in path_len.iter().copied().enumerate() {
+        for _ in 1..=l {
+            poss.push((r, c, dir));
+
+            let (dr, dc): (isize, isize) = match dir {
+                0 => (0, 1),
+                1 => (1, 0),
+                2 => (0, -1),
+                3 => (-1, 0),
+                _ => unimplemented!(),
+            };
+
+            let mut nr: isize = r as isize + dr;
+            let mut nc: isize = c as isize + dc;
+            let mut ndir = dir;
+
+            if nr < map.minir[c] as isize
+                || nr >= map.maxxr[c] as isize
+                || nc < map.minic[r] as isize
+                || nc >= map.maxxc[r] as isize
+            {
+                let face_x: usize = c / cube_side;
+                let face_y: usize = r / cube_side;
+                let connections = map
+                    .cube
+                    .iter()
+                    .find(|conn| conn.from == (face_x, face_y))
+                    .expect("every tile of the map is on a face");
+                let connection = match dir {
+                    0 => &connections.right,
+                    1 => &connections.down,
+                    2 => &connections.left,
+                    3 => &connections.up,
+                    _ => unimplemented!(),
+                };
+
+                let nrl = nr.rem_euclid(cube_side as isize);
+                let ncl = nc.rem_euclid(cube_side as isize);
+
+                let (nclt, nrlt) = rot((ncl, nrl), connection.rot, cube_side);
+
+                nr = (connection.to.1 * cube_side) as isize + nrlt;
+                nc = (connection.to.0 * cube_side) as isize + nclt;
+                ndir = (dir + connection.rot) % 4;
+            }
+
+            if map.walls[usize::try_from(nr).unwrap()][usize::try_from(nc).unwrap()] {
+                break;
+            } else {
+                r = nr as usize;
+                c = nc as usize;
+                dir = ndir;
+            }
+        }
+
+        dir = match path_turn.get(i) {
+            Some(true) => (dir + 1) % 4,
+            Some(false) => (dir + 3) % 4,
+            None => dir,
+        };
+    }
+
+    (r + 1) * 1000 + (c + 1) * 4 + dir
+}
+
+pub struct Day22;
+
+impl Day for Day22 {
+    type Input<'a> = (Map, Vec<usize>, Vec<bool>);
+
+    const EXAMPLES: &'static [Example] = &[Example::new(
+        include_str!("examples/day22.in"),
+        "6032",
+        "5031",
+    )];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut lines_iter = parse::lines(input);
+        let mut map = Map::new(input.lines().count());
+        for (r, mut line) in lines_iter
+            .by_ref()
+            .take_while(|line| !line.is_empty())
+            .enumerate()
+        {
+            map.maxxc.push(0);
+            map.minir
+                .resize(std::cmp::max(map.minir.len(), line.rest().len()), 0);
+            map.maxxr
+                .resize(std::cmp::max(map.maxxr.len(), line.rest().len()), 0);
+            let mut walls = Vec::with_capacity(line.rest().len());
+            for c in 0.. {
+                if line.is_empty() {
+                    break;
+                }
+                let tile = line.one_of(" .#")?;
+                if tile != 0 {
+                    if map.minic.len() <= r {
+                        map.minic.push(c);
+                    }
+                    if map.minir[c] == 0 {
+                        map.minir[c] = r + 1;
+                    }
+                    map.maxxc[r] = c + 1;
+                    map.maxxr[c] = r + 1;
+                }
+                walls.push(tile == 2);
+            }
+            if map.minic.len() <= r {
+                return Err(line.error("one of \".#\""));
+            }
+            map.walls.push(walls);
+        }
+        if map.walls.is_empty() {
+            return Err(parse::first_line(input)?.error("map"));
+        }
+        if map.minir.contains(&0) {
+            return Err(parse::end_of_input(input, "map tile in every column"));
+        }
+        map.minir = map.minir.iter().map(|minir| minir - 1).collect();
+
+        let tiles: usize = map.minic.iter().zip(&map.maxxc).map(|(a, b)| b - a).sum();
+        let cube = (1..)
+            .find(|side| 6 * side * side >= tiles)
+            .filter(|side| 6 * side * side == tiles)
+            .and_then(|side| Some((side, fold_cube(&map, side)?)));
+        match cube {
+            Some((cube_side, cube)) => {
+                map.cube_side = cube_side;
+                map.cube = cube;
+            }
+            None => return Err(parse::first_line(input)?.error("net of a cube")),
+        }
+
+        let mut line = lines_iter
+            .find(|line| !line.is_empty())
+            .ok_or_else(|| parse::end_of_input(input, "path"))?;
+        let mut path_len = vec![line.number()?];
+        let mut path_turn = Vec::new();
+        while !line.is_empty() {
+            path_turn.push(line.one_of("LR")? == 1);
+            path_len.push(line.number()?);
+        }
+
+        Ok((map, path_len, path_turn))
+    }
+
+    fn part_a((map, path_len, path_turn): &Self::Input<'_>, _: &Params) -> String {
+        solve_a(map, path_len, path_turn).to_string()
+    }
+
+    fn part_b((map, path_len, path_turn): &Self::Input<'_>, _: &Params) -> String {
+        solve_b(map, path_len, path_turn).to_string()
+    }
+}
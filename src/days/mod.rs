@@ -1,21 +1,121 @@
-macro_rules! days {
-    ($($day_mod:ident),*) => {
-        $(pub mod $day_mod;)*
+use crate::common::Solver;
+use crate::error::Error;
 
-        pub fn get_solver(day: u8) -> Option<fn(&[String]) -> crate::common::Solution> {
-            match format!("day{:02}", day).as_str() {
-                $(stringify!($day_mod) => Some($day_mod::solve),)*
+macro_rules! days {
+    ($($year:literal => $year_mod:ident::$day_mod:ident::$day_type:ident),*) => {
+        pub fn get_solver(year: u16, day: u8) -> Option<&'static dyn Solver> {
+            match (year, format!("day{:02}", day).as_str()) {
+                $(($year, stringify!($day_mod)) => Some(&$year_mod::$day_mod::$day_type),)*
                 _ => None,
             }
         }
+
+        /// All years with at least one day, in order.
+        pub fn all_years() -> Vec<u16> {
+            let mut years = vec![$($year),*];
+            years.dedup();
+            years
+        }
+    };
+}
+
+pub fn all_numbers(year: u16) -> Vec<u8> {
+    (1..=25)
+        .filter(|&day| get_solver(year, day).is_some())
+        .collect()
+}
+
+/// The most recent year with at least one day, which is run unless another year is chosen.
+pub fn latest_year() -> u16 {
+    all_years().last().copied().unwrap_or(FIRST_YEAR)
+}
+
+/// The first year of Advent of Code.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// Parse a year like `2022`, which must have at least one day.
+pub fn parse_year(spec: &str) -> Result<u16, Error> {
+    let year = spec
+        .trim()
+        .parse()
+        .map_err(|_| Error::InvalidYear(spec.to_string()))?;
+    if all_years().contains(&year) {
+        Ok(year)
+    } else {
+        Err(Error::UnknownYear(year, all_years()))
+    }
+}
+
+/// Parse a selection of days of `year` like `1,3,7-12`. The result is sorted and contains each
+/// day once. Single days must have a solution, but ranges may span days without one, which are
+/// left out.
+pub fn parse_selection(year: u16, spec: &str) -> Result<Vec<u8>, Error> {
+    parse_selection_from(&all_numbers(year), spec)
+}
+
+/// Parse a selection of days like [parse_selection], where the days in `available` may be
+/// selected.
+pub fn parse_selection_from(available: &[u8], spec: &str) -> Result<Vec<u8>, Error> {
+    let parse_day = |day: &str| -> Result<u8, Error> {
+        day.trim()
+            .parse()
+            .map_err(|_| Error::InvalidDay(day.to_string()))
     };
+    let unknown = |day: u8| Error::UnknownDay(day, available.to_vec());
+
+    let mut selected = Vec::new();
+    for item in spec.split(',') {
+        match item.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (parse_day(first)?, parse_day(last)?);
+                if let Some(day) = [first, last]
+                    .into_iter()
+                    .find(|day| !(1..=25).contains(day))
+                {
+                    return Err(unknown(day));
+                }
+                if first > last {
+                    return Err(Error::InvalidDay(item.to_string()));
+                }
+                selected.extend(available.iter().filter(|day| (first..=last).contains(day)));
+            }
+            None => {
+                let day = parse_day(item)?;
+                if !available.contains(&day) {
+                    return Err(unknown(day));
+                }
+                selected.push(day);
+            }
+        }
+    }
+    selected.sort_unstable();
+    selected.dedup();
+    Ok(selected)
 }
 
-pub fn all_numbers() -> Vec<u8> {
-    (1..=25).filter(|&day| get_solver(day).is_some()).collect()
+/// Format a sorted list of days in the same form as [parse_selection] accepts, with consecutive
+/// days as ranges.
+pub fn format_selection(days: &[u8]) -> String {
+    let mut ranges: Vec<(u8, u8)> = Vec::new();
+    for &day in days {
+        match ranges.last_mut() {
+            Some((_, last)) if *last + 1 == day => *last = day,
+            _ => ranges.push((day, day)),
+        }
+    }
+    ranges
+        .into_iter()
+        .map(|(first, last)| {
+            if first == last {
+                first.to_string()
+            } else {
+                format!("{first}-{last}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
 }
 
-days!(
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-);
+// The year and day modules and the call to days! are generated by build.rs from the files in the
+// year directories next to this file.
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
@@ -0,0 +1,212 @@
+use std::fmt::Display;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    InputFile(PathBuf, std::io::Error),
+    InputDirectory(PathBuf, std::io::Error),
+    NoInputFiles(PathBuf),
+    AnswersFile(PathBuf, ParseError),
+    CheckFailed(usize),
+    TimedOut(Vec<u8>),
+    InputsFailed(Vec<String>),
+    Regression(Vec<u8>, f64),
+    InvalidBaseline(PathBuf, String),
+    Parse(ParseError),
+    InvalidDay(String),
+    InvalidYear(String),
+    UnknownYear(u16, Vec<u16>),
+    UnknownDay(u8, Vec<u8>),
+    InputFileForManyDays,
+    ConfigFile(PathBuf, ParseError),
+    MissingSession,
+    Http(String, String),
+    Unsubmittable(String),
+    NotInRepository(PathBuf),
+    DayExists(PathBuf),
+    InvalidParam(String, String),
+    UnknownParam(String, Vec<String>),
+    StreamingUnsupported(u8),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+            Self::InputFile(path, err) => {
+                write!(f, "Failed to read input file {}: {err}", path.display())
+            }
+            Self::InputDirectory(path, err) => {
+                write!(f, "Failed to read input directory {}: {err}", path.display())
+            }
+            Self::NoInputFiles(path) => write!(f, "No input files in {}", path.display()),
+            Self::AnswersFile(path, err) => {
+                write!(f, "Invalid answers file {}: {err}", path.display())
+            }
+            Self::CheckFailed(count) => write!(f, "{count} answer(s) did not match"),
+            Self::TimedOut(days) => write!(
+                f,
+                "Timed out: day {}",
+                crate::days::format_selection(days)
+            ),
+            Self::InputsFailed(inputs) => write!(
+                f,
+                "Failed to solve {} input(s): {}",
+                inputs.len(),
+                inputs.join(", ")
+            ),
+            Self::Regression(days, threshold) => write!(
+                f,
+                "Slower than the baseline by more than {threshold}%: day {}",
+                crate::days::format_selection(days)
+            ),
+            Self::InvalidBaseline(path, message) => {
+                write!(f, "Invalid baseline file {}: {message}", path.display())
+            }
+            Self::Parse(err) => write!(f, "Invalid input: {err}"),
+            Self::InvalidDay(day) => write!(f, "Invalid day selection: {day}"),
+            Self::InvalidYear(year) => write!(f, "Invalid year: {year}"),
+            Self::UnknownYear(year, available) => write!(
+                f,
+                "Unknown year: {year} (available years: {})",
+                available
+                    .iter()
+                    .map(u16::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::UnknownDay(day, available) => write!(
+                f,
+                "Unknown day: {day} (available days: {})",
+                crate::days::format_selection(available)
+            ),
+            Self::ConfigFile(path, err) => {
+                write!(f, "Invalid config file {}: {err}", path.display())
+            }
+            Self::MissingSession => write!(
+                f,
+                "No session cookie: set \"session\" in the config file or the AOC_SESSION environment variable"
+            ),
+            Self::Http(url, message) => write!(f, "Request to {url} failed: {message}"),
+            Self::NotInRepository(path) => write!(
+                f,
+                "Directory {} not found; run this from the repository root",
+                path.display()
+            ),
+            Self::DayExists(path) => write!(f, "{} already exists", path.display()),
+            Self::StreamingUnsupported(day) => {
+                write!(f, "Day {day} cannot be solved with streaming input")
+            }
+            Self::InvalidParam(param, expected) => {
+                write!(f, "Invalid parameter: {param} (expected {expected})")
+            }
+            Self::UnknownParam(name, available) if available.is_empty() => {
+                write!(f, "Unknown parameter: {name} (the selected days have no parameters)")
+            }
+            Self::UnknownParam(name, available) => write!(
+                f,
+                "Unknown parameter: {name} (parameters of the selected days: {})",
+                available.join(", ")
+            ),
+            Self::Unsubmittable(answer) => {
+                write!(f, "Answer cannot be submitted automatically: {answer:?}")
+            }
+            Self::InputFileForManyDays => {
+                write!(
+                    f,
+                    "Input files can only be given when running a single day"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) | Self::InputFile(_, err) | Self::InputDirectory(_, err) => Some(err),
+            Self::AnswersFile(_, err) | Self::ConfigFile(_, err) | Self::Parse(err) => Some(err),
+            Self::CheckFailed(_)
+            | Self::TimedOut(_)
+            | Self::InputsFailed(_)
+            | Self::Regression(..)
+            | Self::InvalidBaseline(..)
+            | Self::NoInputFiles(_)
+            | Self::InvalidDay(_)
+            | Self::InvalidYear(_)
+            | Self::UnknownYear(..)
+            | Self::UnknownDay(..)
+            | Self::InputFileForManyDays
+            | Self::MissingSession
+            | Self::Http(..)
+            | Self::Unsubmittable(_)
+            | Self::NotInRepository(_)
+            | Self::DayExists(_)
+            | Self::InvalidParam(..)
+            | Self::UnknownParam(..)
+            | Self::StreamingUnsupported(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+/// A syntax error in a puzzle input. Line and column numbers are 1-based, and `found` is `None`
+/// if the error occurred at the end of the line.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: Option<String>,
+}
+
+impl ParseError {
+    pub fn new<S: ToString>(line: usize, column: usize, expected: S, found: Option<&str>) -> Self {
+        Self {
+            day: None,
+            line,
+            column,
+            expected: expected.to_string(),
+            found: found.map(str::to_string),
+        }
+    }
+
+    pub fn in_day(self, day: u8) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        match &self.found {
+            Some(found) => write!(f, "{found:?}"),
+            None => write!(f, "end of line"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -0,0 +1,99 @@
+use std::path::Path;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use crate::config::Config;
+use crate::crate_info::crate_name;
+use crate::crate_info::crate_version;
+use adventofcode_2022::common::year_input_filename;
+use adventofcode_2022::error::Error;
+
+/// Puzzles unlock at midnight EST, which is 05:00 UTC.
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+pub enum FetchStatus {
+    Cached,
+    Downloaded,
+}
+
+/// Days since 1970-01-01 of the given date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    let days = days_from_civil(i64::from(year), 12, i64::from(day));
+    UNIX_EPOCH + Duration::from_secs(days as u64 * 86400 + UNLOCK_HOUR_UTC * 3600)
+}
+
+pub fn is_unlocked(year: u16, day: u8) -> bool {
+    SystemTime::now() >= unlock_time(year, day)
+}
+
+fn wait_until_unlocked(year: u16, day: u8) {
+    if let Ok(remaining) = unlock_time(year, day).duration_since(SystemTime::now()) {
+        eprintln!(
+            "Waiting until {year}-12-{day:02}T{UNLOCK_HOUR_UTC:02}:00:00Z ({} s)",
+            remaining.as_secs() + 1
+        );
+        std::thread::sleep(remaining + Duration::from_secs(1));
+    }
+}
+
+pub fn agent() -> ureq::Agent {
+    ureq::AgentBuilder::new()
+        .user_agent(&format!("{} {}", crate_name(), crate_version()))
+        .build()
+}
+
+pub fn http_error(url: &str, err: ureq::Error) -> Error {
+    let message = match err {
+        ureq::Error::Status(status, response) => {
+            let body = response.into_string().unwrap_or_default();
+            format!("HTTP status {status}: {}", body.trim())
+        }
+        ureq::Error::Transport(err) => err.to_string(),
+    };
+    Error::Http(url.to_string(), message)
+}
+
+/// Download the input for `day` of `year` to [year_input_filename], unless it is already there.
+/// Inputs are always saved in the directory for the year, so that inputs of other years are not
+/// mistaken for them. Waits until the puzzle unlocks if necessary.
+pub fn fetch_input(config: &Config, year: u16, day: u8) -> Result<FetchStatus, Error> {
+    let path = year_input_filename(year, day);
+    if path.exists() {
+        return Ok(FetchStatus::Cached);
+    }
+
+    let session = config.session()?;
+    wait_until_unlocked(year, day);
+
+    let url = format!("{}/{year}/day/{day}/input", config.base_url);
+    let input = agent()
+        .get(&url)
+        .set("Cookie", &format!("session={session}"))
+        .call()
+        .map_err(|err| http_error(&url, err))?
+        .into_string()?;
+
+    write_atomically(&path, &input)?;
+    Ok(FetchStatus::Downloaded)
+}
+
+/// Write to a temporary file first, so that an interrupted download is never mistaken for a
+/// cached input.
+fn write_atomically(path: &Path, contents: &str) -> Result<(), std::io::Error> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let tmp_path = path.with_extension("in.tmp");
+    std::fs::write(&tmp_path, contents)?;
+    std::fs::rename(&tmp_path, path)
+}
@@ -1,5 +1,9 @@
+pub mod answers;
+pub mod baseline;
+pub mod cancel;
 pub mod common;
 pub mod days;
+pub mod error;
 pub mod search;
 
 mod util;
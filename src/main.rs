@@ -1,64 +1,1028 @@
+mod bench;
+mod config;
 mod crate_info;
+mod fetch;
+mod memory;
+mod output;
+mod parallel;
+mod report;
+mod scaffold;
+mod submit;
+mod timing;
 
 use clap::App;
 use clap::Arg;
+use clap::ArgMatches;
+use clap::SubCommand;
+use std::io::BufRead;
+use std::panic::AssertUnwindSafe;
 use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
 
+use crate::config::default_config_path;
+use crate::config::Config;
 use crate::crate_info::crate_author;
 use crate::crate_info::crate_description;
 use crate::crate_info::crate_name;
 use crate::crate_info::crate_version;
+use crate::fetch::FetchStatus;
+use crate::memory::measured;
+use crate::memory::CountingAllocator;
+use crate::output::DayResult;
+use crate::output::Format;
+use crate::output::Printer;
+use crate::parallel::map_ordered;
+use crate::parallel::run_with_timeout;
+use crate::report::DayReport;
+use crate::submit::GuessLog;
+use crate::timing::cpu_timed;
+use crate::timing::format_duration;
+use crate::timing::percentile;
+use crate::timing::timed;
+use adventofcode_2022::answers::Answers;
+use adventofcode_2022::answers::Check;
+use adventofcode_2022::baseline::Baseline;
+use adventofcode_2022::baseline::DayBench;
 use adventofcode_2022::common::day_input_filename;
-use adventofcode_2022::common::get_file_lines;
+use adventofcode_2022::common::normalize_input;
+use adventofcode_2022::common::open_input;
+use adventofcode_2022::common::read_input;
+use adventofcode_2022::common::Params;
+use adventofcode_2022::common::Solution;
+use adventofcode_2022::common::Solver;
 use adventofcode_2022::days;
+use adventofcode_2022::error::Error;
+use adventofcode_2022::search::stats;
+use adventofcode_2022::search::stats::SearchStats;
+use adventofcode_2022::search::stats::Trace;
 
-fn main() -> Result<(), std::io::Error> {
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn main() {
     let cli = App::new(crate_name())
         .version(crate_version())
         .about(crate_description())
         .author(crate_author())
+        .arg(
+            Arg::with_name("year")
+                .long("year")
+                .short("y")
+                .takes_value(true)
+                .global(true)
+                .help(r#"Year of the puzzles, for example "2022". Defaults to the most recent year with solutions."#)
+        )
         .arg(
             Arg::with_name("day")
                 .takes_value(true)
-                .help(r#"Day number (1 - 25) to run. If omitted, all days are run."#)
+                .help(r#"Days to run, as a comma-separated list of day numbers (1 - 25) and ranges, for example "1,3,7-12". If omitted, all days are run."#)
         )
         .arg(
             Arg::with_name("input-file")
                 .takes_value(true)
-                .help(r#"Path to a file containing input for the chosen day. Use "-" for standard input; omit to use "./inputs/day<day>.in"."#)
+                .help(r#"Path to a file containing input for the chosen day. Use "-" for standard input; omit to use "./inputs/<year>/day<day>.in". Only allowed when running a single day."#)
+        )
+        .arg(
+            Arg::with_name("inputs")
+                .long("inputs")
+                .takes_value(true)
+                .value_name("dir")
+                .conflicts_with_all(&["input-file", "example"])
+                .help(r#"Solve every file in the given directory and print a table of answers and timings. Answers files ("*.ans") are used with --check and are not solved. Only allowed when running a single day. Exits with an error if any input fails."#)
+        )
+        .arg(
+            Arg::with_name("example")
+                .long("example")
+                .short("e")
+                .conflicts_with("input-file")
+                .help(r#"Run on the example inputs from the puzzle descriptions instead of the puzzle input. With --check, the answers are compared to those given in the puzzle descriptions."#)
+        )
+        .arg(
+            Arg::with_name("stream")
+                .long("stream")
+                .help(r#"Read the input one line at a time and solve both parts in a single pass, instead of reading the whole input into memory first. Only some days support this. Timings report the whole pass as reading time."#)
+        )
+        .arg(
+            Arg::with_name("warn-input")
+                .long("warn-input")
+                .help(r#"Print a warning for each change made to clean up the input file. A UTF-8 byte order mark, CRLF line endings, trailing whitespace and a missing final newline are always fixed before parsing."#)
+        )
+        .arg(
+            Arg::with_name("param")
+                .long("param")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .value_name("name=value")
+                .help(r#"Override a puzzle constant of the selected days, such as a number of rounds. Can be given several times. Examples set the values given in the puzzle description, which this overrides in turn."#)
+        )
+        .arg(
+            Arg::with_name("skip")
+                .long("skip")
+                .short("s")
+                .takes_value(true)
+                .help(r#"Days to skip, in the same format as the day argument."#)
+        )
+        .arg(
+            Arg::with_name("part")
+                .long("part")
+                .short("p")
+                .takes_value(true)
+                .possible_values(&["a", "b"])
+                .help(r#"Run only the given part of the puzzle. If omitted, both parts are run."#)
+        )
+        .arg(
+            Arg::with_name("time")
+                .long("time")
+                .short("t")
+                .help(r#"Report the time spent reading input, parsing and solving each part. When running all days, also print a summary table."#)
+        )
+        .arg(
+            Arg::with_name("mem")
+                .long("mem")
+                .short("m")
+                .help(r#"Report the peak heap use, total bytes allocated and number of allocations of each day. When running all days, also print a summary table."#)
+        )
+        .arg(
+            Arg::with_name("format")
+                .long("format")
+                .short("f")
+                .takes_value(true)
+                .possible_values(&Format::NAMES)
+                .default_value("text")
+                .help(r#"Output format. JSON and CSV output always include timings."#)
+        )
+        .arg(
+            Arg::with_name("check")
+                .long("check")
+                .short("c")
+                .help(r#"Compare the answers to the answers file next to the input file, for example "./inputs/2022/day01.ans", and report PASS, FAIL or UNKNOWN for each part. Exits with an error if any answer is wrong."#)
+        )
+        .arg(
+            Arg::with_name("jobs")
+                .long("jobs")
+                .short("j")
+                .takes_value(true)
+                .default_value("1")
+                .validator(|jobs| match jobs.parse::<usize>() {
+                    Ok(jobs) if jobs > 0 => Ok(()),
+                    _ => Err(format!("Invalid number of jobs: {jobs}")),
+                })
+                .help(r#"Number of days to solve in parallel. Results are still printed in day order, followed by the total wall time compared with the summed CPU time of all days."#)
+        )
+        .arg(
+            Arg::with_name("timeout")
+                .long("timeout")
+                .takes_value(true)
+                .value_name("secs")
+                .validator(|secs| match secs.parse::<f64>() {
+                    Ok(secs) if secs > 0.0 && secs.is_finite() => Ok(()),
+                    _ => Err(format!("Invalid timeout: {secs}")),
+                })
+                .help(r#"Stop each day after the given number of seconds, report it as TIMEOUT and go on with the next day. Exits with an error if any day timed out."#)
+        )
+        .arg(
+            Arg::with_name("search-stats")
+                .long("search-stats")
+                .help(r#"Report how many states the searches of each day popped off the queue, pruned as already visited with a better value or by their upper bound, and pushed onto the queue, the peak queue size and the time spent searching."#)
+        )
+        .arg(
+            Arg::with_name("search-trace")
+                .long("search-trace")
+                .takes_value(true)
+                .value_name("n")
+                .validator(|n| match n.parse::<usize>() {
+                    Ok(n) if n > 0 => Ok(()),
+                    _ => Err(format!("Invalid trace interval: {n}")),
+                })
+                .help(r#"Print every n-th state expanded by the searches of each day to standard error, along with the search statistics so far, and the plan each day chose if it can describe one."#)
+        )
+        .subcommand(
+            SubCommand::with_name("fetch")
+                .about("Download puzzle inputs to ./inputs/<year>/day<day>.in. Inputs that are already downloaded are never downloaded again.")
+                .arg(
+                    Arg::with_name("day")
+                        .takes_value(true)
+                        .help(r#"Days to download, in the same format as when running days. If omitted, all days unlocked so far are downloaded. Waits for locked days to unlock."#)
+                )
+                .args(&website_args()),
+        )
+        .subcommand(
+            SubCommand::with_name("submit")
+                .about("Solve one part of a day and submit the answer. Submitted answers are logged in ./inputs/<year>/day<day>.guesses, and answers known to be wrong are not submitted again.")
+                .arg(
+                    Arg::with_name("day")
+                        .required(true)
+                        .help(r#"Day number (1 - 25) to submit."#)
+                )
+                .arg(
+                    Arg::with_name("part")
+                        .required(true)
+                        .possible_values(&["a", "b"])
+                        .help(r#"Part of the puzzle to submit."#)
+                )
+                .args(&website_args()),
+        )
+        .subcommand(
+            SubCommand::with_name("report")
+                .about("Run days several times and write a Markdown report of the answers and median run times.")
+                .arg(
+                    Arg::with_name("day")
+                        .takes_value(true)
+                        .help(r#"Days to include, in the same format as when running days. If omitted, all days are included."#)
+                )
+                .arg(
+                    Arg::with_name("iterations")
+                        .long("iterations")
+                        .short("n")
+                        .takes_value(true)
+                        .default_value("10")
+                        .validator(|iterations| match iterations.parse::<usize>() {
+                            Ok(iterations) if iterations > 0 => Ok(()),
+                            _ => Err(format!("Invalid number of iterations: {iterations}")),
+                        })
+                        .help(r#"Number of times to run each day. The report shows the median time."#)
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .short("o")
+                        .takes_value(true)
+                        .help(r#"File to write the report to. If omitted, the report is printed to standard output."#)
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("bench")
+                .about("Time each day over several runs and report the median and 95th percentile. Results can be saved as a baseline, and later runs compared against it.")
+                .arg(
+                    Arg::with_name("day")
+                        .takes_value(true)
+                        .help(r#"Days to time, in the same format as when running days. If omitted, all days are timed."#)
+                )
+                .arg(
+                    Arg::with_name("iterations")
+                        .long("iterations")
+                        .short("n")
+                        .takes_value(true)
+                        .default_value("10")
+                        .validator(|iterations| match iterations.parse::<usize>() {
+                            Ok(iterations) if iterations > 0 => Ok(()),
+                            _ => Err(format!("Invalid number of iterations: {iterations}")),
+                        })
+                        .help(r#"Number of times to run each day."#)
+                )
+                .arg(
+                    Arg::with_name("save")
+                        .long("save")
+                        .takes_value(true)
+                        .value_name("file")
+                        .help(r#"Save the results to a file, to use as a baseline later."#)
+                )
+                .arg(
+                    Arg::with_name("baseline")
+                        .long("baseline")
+                        .takes_value(true)
+                        .value_name("file")
+                        .help(r#"Compare the median times to a baseline saved with --save, and exit with an error if any day got slower by more than the threshold."#)
+                )
+                .arg(
+                    Arg::with_name("threshold")
+                        .long("threshold")
+                        .takes_value(true)
+                        .value_name("percent")
+                        .default_value("10")
+                        .validator(|threshold| match threshold.parse::<f64>() {
+                            Ok(threshold) if threshold >= 0.0 && threshold.is_finite() => Ok(()),
+                            _ => Err(format!("Invalid threshold: {threshold}")),
+                        })
+                        .help(r#"How much slower than the baseline, in percent, a day's median time may get before it counts as a regression."#)
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("new")
+                .about("Create ./src/days/<year>/day<day>.rs from a template, and an empty example input in ./src/days/<year>/examples/. New days and years are registered automatically when building.")
+                .arg(
+                    Arg::with_name("day")
+                        .required(true)
+                        .help(r#"Day number (1 - 25) to create."#)
+                ),
         );
 
     let matches = cli.get_matches();
+    let result = match matches.subcommand() {
+        ("fetch", Some(matches)) => fetch(matches),
+        ("submit", Some(matches)) => submit(matches),
+        ("new", Some(matches)) => new_day(matches),
+        ("report", Some(matches)) => report(matches),
+        ("bench", Some(matches)) => bench(matches),
+        _ => run(&matches),
+    };
 
-    if let Some(day) = matches.value_of("day") {
-        run_day(
-            day.parse::<u8>().expect("Invalid day number: {day}"),
-            matches.value_of("input-file").map(Path::new),
-        )
+    if let Err(err) = result {
+        eprintln!("Error: {err}");
+        std::process::exit(1);
+    }
+}
+
+/// Options for subcommands that talk to the Advent of Code website.
+fn website_args() -> Vec<Arg<'static, 'static>> {
+    vec![
+        Arg::with_name("config")
+            .long("config")
+            .takes_value(true)
+            .help(r#"Path to a config file with "session" and "base_url" settings. Defaults to "$XDG_CONFIG_HOME/adventofcode/config". The environment variables AOC_SESSION and AOC_BASE_URL take precedence over the config file."#),
+        Arg::with_name("base-url")
+            .long("base-url")
+            .takes_value(true)
+            .help(r#"Base URL of the Advent of Code website. Takes precedence over the config file and environment."#),
+    ]
+}
+
+fn load_config(matches: &ArgMatches) -> Result<Config, Error> {
+    let path = matches
+        .value_of("config")
+        .map(Into::into)
+        .or_else(default_config_path);
+    let mut config = Config::load(path.as_deref())?;
+    if let Some(base_url) = matches.value_of("base-url") {
+        config.base_url = base_url.to_string();
+    }
+    Ok(config)
+}
+
+/// The year given with `--year`, or the most recent year with solutions.
+fn selected_year(matches: &ArgMatches) -> Result<u16, Error> {
+    match matches.value_of("year") {
+        Some(year) => days::parse_year(year),
+        None => Ok(days::latest_year()),
+    }
+}
+
+/// The selected year, which need not have any days yet, for commands that work on puzzles
+/// instead of solutions.
+fn selected_puzzle_year(matches: &ArgMatches) -> Result<u16, Error> {
+    match matches.value_of("year") {
+        Some(year_arg) => year_arg
+            .parse()
+            .ok()
+            .filter(|year| *year >= days::FIRST_YEAR)
+            .ok_or_else(|| Error::InvalidYear(year_arg.to_string())),
+        None => Ok(days::latest_year()),
+    }
+}
+
+fn fetch(matches: &ArgMatches) -> Result<(), Error> {
+    let config = load_config(matches)?;
+    // Inputs may be downloaded before there is a solution for the day
+    let year = selected_puzzle_year(matches)?;
+    let all_days: Vec<u8> = (1..=25).collect();
+    let days = match matches.value_of("day") {
+        Some(selection) => days::parse_selection_from(&all_days, selection)?,
+        None => all_days
+            .into_iter()
+            .filter(|day| fetch::is_unlocked(year, *day))
+            .collect(),
+    };
+    for day in days {
+        match fetch::fetch_input(&config, year, day)? {
+            FetchStatus::Cached => println!("Day {day: >2}: already downloaded"),
+            FetchStatus::Downloaded => println!("Day {day: >2}: downloaded"),
+        }
+    }
+    Ok(())
+}
+
+fn submit(matches: &ArgMatches) -> Result<(), Error> {
+    let config = load_config(matches)?;
+    let year = selected_year(matches)?;
+    let day_arg = matches.value_of("day").unwrap_or_default();
+    let day = day_arg
+        .parse()
+        .map_err(|_| Error::InvalidDay(day_arg.to_string()))?;
+    let part = matches.value_of("part").unwrap_or("a");
+
+    let solver = days::get_solver(year, day)
+        .ok_or_else(|| Error::UnknownDay(day, days::all_numbers(year)))?;
+    let input = read_input(&day_input_filename(year, day))?;
+    let (input, _) = normalize_input(&input);
+    let input = solver.parse(&input).map_err(|err| err.in_day(day))?;
+    let answer = if part == "a" {
+        input.part_a()
     } else {
-        run_all_days()
+        input.part_b()
+    };
+    if answer.is_empty() || answer.contains('\n') {
+        return Err(Error::Unsubmittable(answer));
+    }
+
+    let mut guesses = GuessLog::read(&GuessLog::path(year, day))?;
+    if let Some(reason) = guesses.known_verdict(part, &answer) {
+        println!("Not submitting {answer}: {reason}");
+        return Ok(());
     }
+
+    println!("Submitting {year} day {day} part {part}: {answer}");
+    let verdict = submit::submit_answer(&config, year, day, part, &answer)?;
+    println!("{verdict}");
+    guesses.record(part, verdict, &answer)
 }
 
-fn run_day(day: u8, input_path: Option<&Path>) -> Result<(), std::io::Error> {
-    println!();
-    println!("=== Day {day: >2} ===");
+fn new_day(matches: &ArgMatches) -> Result<(), Error> {
+    // A new day may start a new year
+    let year = selected_puzzle_year(matches)?;
+    let day_arg = matches.value_of("day").unwrap_or_default();
+    let day = day_arg
+        .parse()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| Error::InvalidDay(day_arg.to_string()))?;
+    for path in scaffold::new_day(year, day)? {
+        println!("Created {}", path.display());
+    }
+    Ok(())
+}
 
-    let day_func = days::get_solver(day).expect("Unknown day: {day}");
-    let lines = input_path
-        .map(get_file_lines)
-        .unwrap_or_else(|| get_file_lines(&day_input_filename(day)))?;
-    let solution = day_func(&lines);
+fn report(matches: &ArgMatches) -> Result<(), Error> {
+    let year = selected_year(matches)?;
+    let days = select_days(year, matches.value_of("day"), None)?;
+    let iterations = matches
+        .value_of("iterations")
+        .and_then(|iterations| iterations.parse().ok())
+        .unwrap_or(10);
 
-    println!("A: {}", solution.0);
-    println!("B: {}", solution.1);
+    let mut reports = Vec::with_capacity(days.len());
+    for day in days {
+        let report = report_day(year, day, iterations)?;
+        eprintln!("Day {day: >2}: {}", format_duration(report.median));
+        reports.push(report);
+    }
+
+    let markdown = report::render(year, iterations, &reports);
+    match matches.value_of("output") {
+        Some(path) => std::fs::write(path, markdown)?,
+        None => print!("{markdown}"),
+    }
+    Ok(())
+}
+
+fn report_day(year: u16, day: u8, iterations: usize) -> Result<DayReport, Error> {
+    let ((answer_a, answer_b), times) = time_day(year, day, iterations)?;
+    Ok(DayReport {
+        day,
+        answer_a,
+        answer_b,
+        median: percentile(&times, 50),
+    })
+}
+
+/// Solve `day` `iterations` times with its default parameters, and return the answers and how
+/// long each run took to parse the input and solve both parts.
+fn time_day(year: u16, day: u8, iterations: usize) -> Result<(Solution, Vec<Duration>), Error> {
+    let solver = days::get_solver(year, day)
+        .ok_or_else(|| Error::UnknownDay(day, days::all_numbers(year)))?;
+    let input = read_input(&day_input_filename(year, day))?;
+    let (input, _) = normalize_input(&input);
+    let mut times = Vec::with_capacity(iterations);
+    let mut answers = (String::new(), String::new());
+    for _ in 0..iterations {
+        let (solution, time) = timed(|| solver.solve(&input));
+        answers = solution.map_err(|err| err.in_day(day))?;
+        times.push(time);
+    }
+    Ok((answers, times))
+}
+
+fn bench(matches: &ArgMatches) -> Result<(), Error> {
+    let year = selected_year(matches)?;
+    let days = select_days(year, matches.value_of("day"), None)?;
+    let iterations = matches
+        .value_of("iterations")
+        .and_then(|iterations| iterations.parse().ok())
+        .unwrap_or(10);
+    let threshold = matches
+        .value_of("threshold")
+        .and_then(|threshold| threshold.parse().ok())
+        .unwrap_or(10.0);
+    let baseline = match matches.value_of("baseline") {
+        Some(path) => Some(Baseline::read(Path::new(path), year)?),
+        None => None,
+    };
+
+    let mut results = Vec::with_capacity(days.len());
+    let mut regressions = Vec::new();
+    bench::print_header(baseline.is_some());
+    for day in days {
+        let (_, times) = time_day(year, day, iterations)?;
+        let result = DayBench {
+            day,
+            median: percentile(&times, 50),
+            p95: percentile(&times, 95),
+        };
+        if bench::print_day(&result, baseline.as_ref(), threshold) {
+            regressions.push(day);
+        }
+        results.push(result);
+    }
+
+    if let Some(path) = matches.value_of("save") {
+        let baseline = Baseline {
+            year,
+            iterations,
+            days: results,
+        };
+        baseline.write(Path::new(path))?;
+        println!("Saved baseline to {path}");
+    }
+    if regressions.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Regression(regressions, threshold))
+    }
+}
+
+fn run(matches: &ArgMatches) -> Result<(), Error> {
+    if matches.is_present("mem") {
+        memory::enable();
+    }
+    let year = selected_year(matches)?;
+    let days = select_days(year, matches.value_of("day"), matches.value_of("skip"))?;
+    let options = RunOptions {
+        year,
+        part: matches.value_of("part").map(str::to_string),
+        time: matches.is_present("time"),
+        check: matches.is_present("check"),
+        jobs: matches
+            .value_of("jobs")
+            .and_then(|jobs| jobs.parse().ok())
+            .unwrap_or(1),
+        example: matches.is_present("example"),
+        stream: matches.is_present("stream"),
+        warn_input: matches.is_present("warn-input"),
+        mem: matches.is_present("mem"),
+        format: matches
+            .value_of("format")
+            .and_then(Format::from_name)
+            .unwrap_or(Format::Text),
+        params: parse_params(
+            year,
+            matches.values_of("param").into_iter().flatten(),
+            &days,
+        )?,
+        timeout: matches
+            .value_of("timeout")
+            .and_then(|secs| secs.parse().ok())
+            .map(Duration::from_secs_f64),
+        search_stats: matches.is_present("search-stats"),
+        search_trace: matches
+            .value_of("search-trace")
+            .and_then(|n| n.parse().ok()),
+    };
+
+    let input_path = matches.value_of("input-file").map(Path::new);
+    let inputs_dir = matches.value_of("inputs").map(Path::new);
+    if (input_path.is_some() || inputs_dir.is_some()) && days.len() != 1 {
+        Err(Error::InputFileForManyDays)
+    } else if let Some(dir) = inputs_dir {
+        run_batch(days[0], dir, &options)
+    } else {
+        run_days(&days, input_path, &options)
+    }
+}
+
+#[derive(Clone)]
+struct RunOptions {
+    year: u16,
+    part: Option<String>,
+    time: bool,
+    check: bool,
+    jobs: usize,
+    example: bool,
+    stream: bool,
+    warn_input: bool,
+    mem: bool,
+    format: Format,
+    params: Vec<(String, i64)>,
+    timeout: Option<Duration>,
+    search_stats: bool,
+    /// Trace every n-th expanded search state, if set.
+    search_trace: Option<usize>,
+}
+
+fn select_days(year: u16, selection: Option<&str>, skip: Option<&str>) -> Result<Vec<u8>, Error> {
+    let mut selected = match selection {
+        Some(selection) => days::parse_selection(year, selection)?,
+        None => days::all_numbers(year),
+    };
+    if let Some(skip) = skip {
+        let skipped = days::parse_selection(year, skip)?;
+        selected.retain(|day| !skipped.contains(day));
+    }
+    Ok(selected)
+}
+
+/// Parse `name=value` parameter overrides, each of which must be a parameter of at least one of
+/// `days`.
+fn parse_params<'a>(
+    year: u16,
+    args: impl Iterator<Item = &'a str>,
+    days: &[u8],
+) -> Result<Vec<(String, i64)>, Error> {
+    let schemas: Vec<_> = days
+        .iter()
+        .filter_map(|&day| Some((day, days::get_solver(year, day)?.params())))
+        .collect();
+    args.map(|arg| {
+        let (name, value) = arg
+            .split_once('=')
+            .and_then(|(name, value)| Some((name.trim(), value.trim().parse().ok()?)))
+            .ok_or_else(|| Error::InvalidParam(arg.to_string(), "name=value".to_string()))?;
+        let params: Vec<_> = schemas
+            .iter()
+            .flat_map(|(day, schema)| {
+                schema
+                    .iter()
+                    .filter(|param| param.name == name)
+                    .map(move |param| (day, param))
+            })
+            .collect();
+        if params.is_empty() {
+            return Err(Error::UnknownParam(
+                name.to_string(),
+                schemas
+                    .iter()
+                    .flat_map(|(day, schema)| {
+                        schema.iter().map(move |param| {
+                            format!("{}={} (day {day})", param.name, param.default)
+                        })
+                    })
+                    .collect(),
+            ));
+        }
+        match params
+            .iter()
+            .find(|(_, param)| !(param.min..=param.max).contains(&value))
+        {
+            Some((day, param)) => Err(Error::InvalidParam(
+                arg.to_string(),
+                format!("{name} from {} to {} for day {day}", param.min, param.max),
+            )),
+            None => Ok((name.to_string(), value)),
+        }
+    })
+    .collect()
+}
+
+fn run_day(
+    day: u8,
+    input_path: Option<&Path>,
+    options: &RunOptions,
+) -> Result<Vec<DayResult>, Error> {
+    let solver = days::get_solver(options.year, day)
+        .ok_or_else(|| Error::UnknownDay(day, days::all_numbers(options.year)))?;
+
+    if options.example {
+        solver
+            .examples()
+            .iter()
+            .enumerate()
+            .map(|(i, example)| {
+                let mut result = DayResult::new(options.year, day, format!("example {}", i + 1));
+                result.example = Some(i + 1);
+                result.answers = options.check.then(|| Answers {
+                    a: example.answer_a.map(str::to_string),
+                    b: example.answer_b.map(str::to_string),
+                });
+                let params = day_params(solver, example.params, options);
+                let (((solved, search), memory), cpu_time) = cpu_timed(|| {
+                    measured(|| {
+                        instrumented(day, options, || {
+                            if options.stream {
+                                let mut input = example.input.as_bytes();
+                                solve_stream(solver, &mut input, params, &mut result, options)
+                            } else {
+                                solve(solver, example.input, params, &mut result, options)
+                            }
+                        })
+                    })
+                });
+                solved?;
+                result.cpu_time = cpu_time;
+                result.memory = options.mem.then_some(memory);
+                result.search = search;
+                Ok(result)
+            })
+            .collect()
+    } else {
+        let input_path = input_path
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| day_input_filename(options.year, day));
+        let mut result = DayResult::new(options.year, day, input_path.display().to_string());
+
+        if options.check {
+            let answers = Answers::read_for_day(options.year, day, &input_path)?;
+            result.answers = Some(answers.unwrap_or_default());
+        }
+
+        let params = day_params(solver, &[], options);
+        let (((solved, search), memory), cpu_time) = cpu_timed(|| {
+            measured(|| {
+                instrumented(day, options, || {
+                    if options.stream {
+                        let mut reader = open_input(&input_path)?;
+                        solve_stream(solver, &mut reader, params, &mut result, options).map_err(
+                            |err| match err {
+                                Error::Io(err) => Error::InputFile(input_path.clone(), err),
+                                err => err,
+                            },
+                        )
+                    } else {
+                        let (input, read_time) = timed(|| read_input(&input_path));
+                        let input = input?;
+                        result.timing.read = read_time;
+                        let (input, changes) = normalize_input(&input);
+                        if options.warn_input {
+                            for change in changes {
+                                eprintln!("Warning: {}: {change}", input_path.display());
+                            }
+                        }
+                        solve(solver, &input, params, &mut result, options)
+                    }
+                })
+            })
+        });
+        solved?;
+        result.cpu_time = cpu_time;
+        result.memory = options.mem.then_some(memory);
+        result.search = search;
+        Ok(vec![result])
+    }
+}
+
+/// Run `f`, which solves `day`, collecting statistics of its searches if running with
+/// `--search-stats` and tracing expanded states if running with `--search-trace`.
+fn instrumented<T>(
+    day: u8,
+    options: &RunOptions,
+    f: impl FnOnce() -> T,
+) -> (T, Option<SearchStats>) {
+    let collect = || {
+        if options.search_stats {
+            let (result, stats) = stats::collect(f);
+            (result, Some(stats))
+        } else {
+            (f(), None)
+        }
+    };
+    match options.search_trace {
+        Some(every) => stats::traced(
+            move |trace: Trace| match trace {
+                Trace::Expanded(stats, state) => {
+                    if stats.popped.is_multiple_of(every) {
+                        eprintln!(
+                            "Day {day}: popped {}, pruned {}, pushed {}, peak queue {}: {state:?}",
+                            stats.popped, stats.pruned, stats.pushed, stats.peak_queue
+                        );
+                    }
+                }
+                Trace::Solution(solution) => eprintln!("Day {day}: solution: {solution:?}"),
+            },
+            collect,
+        ),
+        None => collect(),
+    }
+}
+
+/// Run `day` as [run_day] does, but give up after `timeout` and return a result marked as timed out
+/// instead.
+fn run_day_with_timeout(
+    day: u8,
+    input_path: Option<&Path>,
+    options: &RunOptions,
+    timeout: Duration,
+) -> Result<Vec<DayResult>, Error> {
+    let thread_input_path = input_path.map(Path::to_path_buf);
+    let thread_options = options.clone();
+    run_with_timeout(timeout, move || {
+        run_day(day, thread_input_path.as_deref(), &thread_options)
+    })
+    .unwrap_or_else(|| {
+        let input = if options.example {
+            "examples".to_string()
+        } else {
+            input_path
+                .map(Path::to_path_buf)
+                .unwrap_or_else(|| day_input_filename(options.year, day))
+                .display()
+                .to_string()
+        };
+        let mut result = DayResult::new(options.year, day, input);
+        result.timed_out = Some(timeout);
+        Ok(vec![result])
+    })
+}
+
+/// The parameter values for one run of `solver`: the defaults, overridden by the example if any,
+/// and then by the command line.
+fn day_params(solver: &dyn Solver, example: &[(&str, i64)], options: &RunOptions) -> Params {
+    let mut params = Params::new(solver.params());
+    for (name, value) in example {
+        params.set(name, *value);
+    }
+    for (name, value) in &options.params {
+        params.set(name, *value);
+    }
+    params
+}
+
+fn solve(
+    solver: &dyn Solver,
+    input: &str,
+    params: Params,
+    result: &mut DayResult,
+    options: &RunOptions,
+) -> Result<(), Error> {
+    let (input, parse_time) = timed(|| solver.parse_with_params(input, params));
+    let input = input.map_err(|err| err.in_day(result.day))?;
+    result.timing.parse = parse_time;
+    input.check_params()?;
 
+    if options.part.as_deref() != Some("b") {
+        let (solution, time) = timed(|| input.part_a());
+        result.answer_a = Some(solution);
+        result.timing.part_a = Some(time);
+    }
+    if options.part.as_deref() != Some("a") {
+        let (solution, time) = timed(|| input.part_b());
+        result.answer_b = Some(solution);
+        result.timing.part_b = Some(time);
+    }
     Ok(())
 }
 
-fn run_all_days() -> Result<(), std::io::Error> {
-    for day in days::all_numbers() {
-        run_day(day, None)?
+/// Solve both parts in one pass over `input`, which is read one line at a time. The time of the
+/// whole pass is reported as the read time.
+fn solve_stream(
+    solver: &dyn Solver,
+    input: &mut dyn BufRead,
+    params: Params,
+    result: &mut DayResult,
+    options: &RunOptions,
+) -> Result<(), Error> {
+    let (solution, time) = timed(|| solver.solve_stream(input, &params));
+    let (answer_a, answer_b) = solution
+        .ok_or(Error::StreamingUnsupported(result.day))?
+        .map_err(|err| match err {
+            Error::Parse(err) => Error::Parse(err.in_day(result.day)),
+            err => err,
+        })?;
+    result.timing.read = time;
+
+    if options.part.as_deref() != Some("b") {
+        result.answer_a = Some(answer_a);
+    }
+    if options.part.as_deref() != Some("a") {
+        result.answer_b = Some(answer_b);
     }
     Ok(())
 }
+
+fn run_days(days: &[u8], input_path: Option<&Path>, options: &RunOptions) -> Result<(), Error> {
+    let mut printer = Printer::new(options.format, options.time, options.check, options.mem);
+    let mut results = Vec::with_capacity(days.len());
+    let mut error = None;
+    printer.begin();
+    let ((), wall_time) = timed(|| {
+        map_ordered(
+            days,
+            options.jobs,
+            |&day| match options.timeout {
+                Some(timeout) => run_day_with_timeout(day, input_path, options, timeout),
+                None => run_day(day, input_path, options),
+            },
+            |day_results| match day_results {
+                Ok(day_results) => {
+                    for result in day_results {
+                        printer.day(&result);
+                        results.push(result);
+                    }
+                    true
+                }
+                Err(err) => {
+                    error = Some(err);
+                    false
+                }
+            },
+        )
+    });
+    // Finish even after an error, so that JSON output is still a complete array, but leave out
+    // the summaries of a run that didn't complete.
+    let complete = error.is_none();
+    let show_wall_time = complete && (options.time || options.jobs > 1);
+    printer.finish(
+        &results,
+        complete && results.len() > 1,
+        show_wall_time.then_some(wall_time),
+    );
+    if let Some(err) = error {
+        return Err(err);
+    }
+
+    let timed_out: Vec<u8> = results
+        .iter()
+        .filter(|result| result.timed_out.is_some())
+        .map(|result| result.day)
+        .collect();
+    if !timed_out.is_empty() {
+        return Err(Error::TimedOut(timed_out));
+    }
+
+    let failed = results
+        .iter()
+        .flat_map(DayResult::checks)
+        .filter(|check| *check == Check::Fail)
+        .count();
+    if failed > 0 {
+        Err(Error::CheckFailed(failed))
+    } else {
+        Ok(())
+    }
+}
+
+/// The input files in `dir`, in name order, leaving out answers files and guess logs.
+fn batch_inputs(dir: &Path) -> Result<Vec<PathBuf>, Error> {
+    let read_error = |err| Error::InputDirectory(dir.to_path_buf(), err);
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(dir).map_err(read_error)? {
+        let path = entry.map_err(read_error)?.path();
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        if path.is_file() && !matches!(extension, Some("ans" | "guesses")) {
+            paths.push(path);
+        }
+    }
+    if paths.is_empty() {
+        return Err(Error::NoInputFiles(dir.to_path_buf()));
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Run `day` on one of several inputs. Errors and panics are recorded in the result instead of
+/// ending the run, so that the other inputs are still solved.
+fn run_batch_input(day: u8, path: &Path, options: &RunOptions) -> DayResult {
+    let run = std::panic::catch_unwind(AssertUnwindSafe(|| match options.timeout {
+        Some(timeout) => run_day_with_timeout(day, Some(path), options, timeout),
+        None => run_day(day, Some(path), options),
+    }));
+    let error = match run {
+        Ok(Ok(mut results)) => match results.pop() {
+            Some(result) => return result,
+            None => "no result".to_string(),
+        },
+        Ok(Err(err)) => err.to_string(),
+        Err(panic) => {
+            let message = panic
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("unknown cause");
+            format!("panicked: {message}")
+        }
+    };
+    let mut result = DayResult::new(options.year, day, path.display().to_string());
+    result.error = Some(error);
+    result
+}
+
+fn run_batch(day: u8, dir: &Path, options: &RunOptions) -> Result<(), Error> {
+    let paths = batch_inputs(dir)?;
+    let mut results = Vec::with_capacity(paths.len());
+    map_ordered(
+        &paths,
+        options.jobs,
+        |path| run_batch_input(day, path, options),
+        |result| {
+            results.push(result);
+            true
+        },
+    );
+    Printer::new(options.format, options.time, options.check, options.mem).batch(&results);
+
+    let failed: Vec<String> = results
+        .iter()
+        .filter(|result| result.error.is_some() || result.timed_out.is_some())
+        .map(|result| result.input.clone())
+        .collect();
+    let mismatched = results
+        .iter()
+        .flat_map(DayResult::checks)
+        .filter(|check| *check == Check::Fail)
+        .count();
+    if !failed.is_empty() {
+        Err(Error::InputsFailed(failed))
+    } else if mismatched > 0 {
+        Err(Error::CheckFailed(mismatched))
+    } else {
+        Ok(())
+    }
+}
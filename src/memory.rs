@@ -0,0 +1,133 @@
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::cell::Cell;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Heap use of the current thread. `current` can be negative if the thread frees memory that was
+/// allocated by another thread.
+struct Counters {
+    current: Cell<isize>,
+    peak: Cell<isize>,
+    allocated: Cell<usize>,
+    allocations: Cell<usize>,
+}
+
+thread_local! {
+    static COUNTERS: Counters = const {
+        Counters {
+            current: Cell::new(0),
+            peak: Cell::new(0),
+            allocated: Cell::new(0),
+            allocations: Cell::new(0),
+        }
+    };
+}
+
+/// Heap use while running one day.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MemoryStats {
+    /// The most heap memory in use at once, not counting what was in use before the day started.
+    pub peak: usize,
+    /// The total size of all allocations.
+    pub allocated: usize,
+    pub allocations: usize,
+}
+
+/// The system allocator, but counting the allocations of each thread once [enable] has been
+/// called.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize, 1);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize, 1);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(-(layout.size() as isize), 0);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(-(layout.size() as isize), 0);
+            record(new_size as isize, 1);
+        }
+        new_ptr
+    }
+}
+
+fn record(size: isize, allocations: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    // The counters are unavailable while the thread is shutting down; ignore those allocations.
+    let _ = COUNTERS.try_with(|counters| {
+        let current = counters.current.get() + size;
+        counters.current.set(current);
+        counters.peak.set(counters.peak.get().max(current));
+        if size > 0 {
+            counters
+                .allocated
+                .set(counters.allocated.get() + size as usize);
+        }
+        counters
+            .allocations
+            .set(counters.allocations.get() + allocations);
+    });
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Run `f` and return its result along with the heap use of the current thread while it ran. This
+/// is all zero unless [enable] has been called.
+pub fn measured<T, F: FnOnce() -> T>(f: F) -> (T, MemoryStats) {
+    let (start_current, start_allocated, start_allocations) = COUNTERS.with(|counters| {
+        counters.peak.set(counters.current.get());
+        (
+            counters.current.get(),
+            counters.allocated.get(),
+            counters.allocations.get(),
+        )
+    });
+    let result = f();
+    let stats = COUNTERS.with(|counters| MemoryStats {
+        peak: (counters.peak.get() - start_current).max(0) as usize,
+        allocated: counters.allocated.get() - start_allocated,
+        allocations: counters.allocations.get() - start_allocations,
+    });
+    (result, stats)
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
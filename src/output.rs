@@ -0,0 +1,573 @@
+use std::path::Path;
+use std::time::Duration;
+
+use adventofcode_2022::answers::Answers;
+use adventofcode_2022::answers::Check;
+use adventofcode_2022::search::stats::SearchStats;
+
+use crate::memory::format_bytes;
+use crate::memory::MemoryStats;
+use crate::timing::format_duration;
+use crate::timing::format_optional_duration;
+use crate::timing::millis;
+use crate::timing::DayTiming;
+
+const SLOWEST_DAYS: usize = 3;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub const NAMES: [&'static str; 3] = ["text", "json", "csv"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+pub struct DayResult {
+    pub year: u16,
+    pub day: u8,
+    /// The input file path, or a description of the example input.
+    pub input: String,
+    /// The number of the example, if running on an example input.
+    pub example: Option<usize>,
+    pub answer_a: Option<String>,
+    pub answer_b: Option<String>,
+    pub timing: DayTiming,
+    /// The CPU time of the thread that ran the day, if the platform can measure it.
+    pub cpu_time: Option<Duration>,
+    /// The time limit, if the day was stopped for taking too long.
+    pub timed_out: Option<Duration>,
+    /// The error or panic message, if the day failed. Only set when running several inputs with
+    /// `--inputs`; otherwise errors end the run.
+    pub error: Option<String>,
+    /// Heap use, if running with `--mem`.
+    pub memory: Option<MemoryStats>,
+    /// Statistics of the day's searches, if running with `--search-stats`.
+    pub search: Option<SearchStats>,
+    /// The known answers, if running with `--check`.
+    pub answers: Option<Answers>,
+}
+
+impl DayResult {
+    pub fn new(year: u16, day: u8, input: String) -> Self {
+        Self {
+            year,
+            day,
+            input,
+            example: None,
+            answer_a: None,
+            answer_b: None,
+            timing: DayTiming::default(),
+            cpu_time: None,
+            timed_out: None,
+            error: None,
+            memory: None,
+            search: None,
+            answers: None,
+        }
+    }
+
+    pub fn check_a(&self) -> Option<Check> {
+        Self::check(&self.answers, |answers| &answers.a, &self.answer_a)
+    }
+
+    pub fn check_b(&self) -> Option<Check> {
+        Self::check(&self.answers, |answers| &answers.b, &self.answer_b)
+    }
+
+    fn check<F>(answers: &Option<Answers>, part: F, answer: &Option<String>) -> Option<Check>
+    where
+        F: Fn(&Answers) -> &Option<String>,
+    {
+        let answers = answers.as_ref()?;
+        Some(Check::new(part(answers).as_deref(), answer.as_deref()?))
+    }
+
+    pub fn checks(&self) -> impl Iterator<Item = Check> {
+        self.check_a().into_iter().chain(self.check_b())
+    }
+}
+
+/// Prints results one day at a time in the chosen format.
+pub struct Printer {
+    format: Format,
+    time: bool,
+    check: bool,
+    mem: bool,
+    days_printed: usize,
+}
+
+impl Printer {
+    pub fn new(format: Format, time: bool, check: bool, mem: bool) -> Self {
+        Self {
+            format,
+            time,
+            check,
+            mem,
+            days_printed: 0,
+        }
+    }
+
+    pub fn begin(&self) {
+        if self.format == Format::Csv {
+            println!(
+                "year,day,input,answer_a,answer_b,read_ms,parse_ms,a_ms,b_ms,total_ms,check_a,check_b,timeout,error,peak_bytes,allocated_bytes,allocations,searches,popped,pruned,pushed,peak_queue,search_ms"
+            );
+        }
+    }
+
+    pub fn day(&mut self, result: &DayResult) {
+        match self.format {
+            Format::Text => self.day_text(result),
+            Format::Json => self.day_json(result),
+            Format::Csv => self.day_csv(result),
+        }
+        self.days_printed += 1;
+    }
+
+    /// Print the results of running one day on several inputs. Text output is a table with one row
+    /// per input; the other formats print each result as usual.
+    pub fn batch(&mut self, results: &[DayResult]) {
+        if self.format == Format::Text {
+            print_batch_table(results, self.check);
+        } else {
+            self.begin();
+            for result in results {
+                self.day(result);
+            }
+            self.finish(results, false, None);
+        }
+    }
+
+    /// Finish the output. If `summary` is set, text output ends with tables of all days' timings
+    /// and heap use and a count of checked answers, if requested. If `wall_time` is given, text output ends
+    /// with a comparison of the total wall time and the summed CPU time of all days.
+    pub fn finish(&self, results: &[DayResult], summary: bool, wall_time: Option<Duration>) {
+        match self.format {
+            Format::Text => {
+                if summary && self.time {
+                    print_summary(results);
+                }
+                if summary && self.mem {
+                    print_memory_summary(results);
+                }
+                if summary && self.check {
+                    print_check_summary(results);
+                }
+                if let Some(wall_time) = wall_time {
+                    print_wall_time(results, wall_time);
+                }
+            }
+            Format::Csv => {}
+            Format::Json if self.days_printed == 0 => println!("[]"),
+            Format::Json => println!("]"),
+        }
+    }
+
+    fn day_text(&self, result: &DayResult) {
+        println!();
+        match result.example {
+            Some(example) => println!("=== Day {: >2} (example {example}) ===", result.day),
+            None => println!("=== Day {: >2} ===", result.day),
+        }
+        if let Some(timeout) = result.timed_out {
+            println!("TIMEOUT after {}", format_duration(timeout));
+            return;
+        }
+        if let Some(error) = &result.error {
+            println!("ERROR: {error}");
+            return;
+        }
+        if let Some(answer) = &result.answer_a {
+            println!("A: {answer}");
+        }
+        if let Some(answer) = &result.answer_b {
+            println!("B: {answer}");
+        }
+        if self.check {
+            let describe = |part: &str, check: Option<Check>, expected: &Option<String>| {
+                check.map(|check| match (check, expected) {
+                    (Check::Fail, Some(expected)) => {
+                        format!("{part} {check} (expected {expected:?})")
+                    }
+                    _ => format!("{part} {check}"),
+                })
+            };
+            let answers = result.answers.clone().unwrap_or_default();
+            println!(
+                "Check: {}",
+                describe("A", result.check_a(), &answers.a)
+                    .into_iter()
+                    .chain(describe("B", result.check_b(), &answers.b))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+        if self.time {
+            let timing = &result.timing;
+            println!(
+                "Time: read {}, parse {}, A {}, B {}, total {}",
+                format_duration(timing.read),
+                format_duration(timing.parse),
+                format_optional_duration(timing.part_a),
+                format_optional_duration(timing.part_b),
+                format_duration(timing.total()),
+            );
+        }
+        if let Some(memory) = &result.memory {
+            println!(
+                "Memory: peak {}, allocated {} in {} allocations",
+                format_bytes(memory.peak),
+                format_bytes(memory.allocated),
+                memory.allocations,
+            );
+        }
+        if let Some(search) = &result.search {
+            println!(
+                "Search: {} searches, popped {}, pruned {}, pushed {}, peak queue {}, time {}",
+                search.searches,
+                search.popped,
+                search.pruned,
+                search.pushed,
+                search.peak_queue,
+                format_duration(search.elapsed),
+            );
+        }
+    }
+
+    fn day_json(&self, result: &DayResult) {
+        let answer = |answer: &Option<String>| {
+            answer
+                .as_deref()
+                .map(json_string)
+                .unwrap_or_else(|| "null".to_string())
+        };
+        let check = |check: Option<Check>| {
+            check
+                .map(|check| json_string(&check.to_string()))
+                .unwrap_or_else(|| "null".to_string())
+        };
+        let duration =
+            |duration: Option<Duration>| duration.map(ms).unwrap_or_else(|| "null".to_string());
+        let memory = result
+            .memory
+            .map(|memory| {
+                format!(
+                    "{{\"peak\":{},\"allocated\":{},\"allocations\":{}}}",
+                    memory.peak, memory.allocated, memory.allocations
+                )
+            })
+            .unwrap_or_else(|| "null".to_string());
+        let search = result
+            .search
+            .map(|search| {
+                format!(
+                    "{{\"searches\":{},\"popped\":{},\"pruned\":{},\"pushed\":{},\"peak_queue\":{},\"time_ms\":{}}}",
+                    search.searches,
+                    search.popped,
+                    search.pruned,
+                    search.pushed,
+                    search.peak_queue,
+                    ms(search.elapsed)
+                )
+            })
+            .unwrap_or_else(|| "null".to_string());
+        let timing = &result.timing;
+        println!(
+            "{}{{\"year\":{},\"day\":{},\"input\":{},\"answer_a\":{},\"answer_b\":{},\"time_ms\":{{\"read\":{},\"parse\":{},\"a\":{},\"b\":{},\"total\":{}}},\"check_a\":{},\"check_b\":{},\"timeout\":{},\"error\":{},\"memory\":{},\"search\":{}}}",
+            if self.days_printed == 0 { "[" } else { "," },
+            result.year,
+            result.day,
+            json_string(&result.input),
+            answer(&result.answer_a),
+            answer(&result.answer_b),
+            ms(timing.read),
+            ms(timing.parse),
+            duration(timing.part_a),
+            duration(timing.part_b),
+            ms(timing.total()),
+            check(result.check_a()),
+            check(result.check_b()),
+            result.timed_out.is_some(),
+            answer(&result.error),
+            memory,
+            search,
+        );
+    }
+
+    fn day_csv(&self, result: &DayResult) {
+        let optional = |value: Option<String>| value.unwrap_or_default();
+        let timing = &result.timing;
+        let memory = result.memory;
+        let search = result.search;
+        println!(
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            result.year,
+            result.day,
+            csv_field(&result.input),
+            csv_field(result.answer_a.as_deref().unwrap_or("")),
+            csv_field(result.answer_b.as_deref().unwrap_or("")),
+            ms(timing.read),
+            ms(timing.parse),
+            optional(timing.part_a.map(ms)),
+            optional(timing.part_b.map(ms)),
+            ms(timing.total()),
+            optional(result.check_a().map(|check| check.to_string())),
+            optional(result.check_b().map(|check| check.to_string())),
+            result.timed_out.is_some(),
+            csv_field(result.error.as_deref().unwrap_or("")),
+            optional(memory.map(|memory| memory.peak.to_string())),
+            optional(memory.map(|memory| memory.allocated.to_string())),
+            optional(memory.map(|memory| memory.allocations.to_string())),
+            optional(search.map(|search| search.searches.to_string())),
+            optional(search.map(|search| search.popped.to_string())),
+            optional(search.map(|search| search.pruned.to_string())),
+            optional(search.map(|search| search.pushed.to_string())),
+            optional(search.map(|search| search.peak_queue.to_string())),
+            optional(search.map(|search| ms(search.elapsed))),
+        );
+    }
+}
+
+fn print_summary(results: &[DayResult]) {
+    println!();
+    println!("=== Timing ===");
+    println!(
+        "{:>3}  {:>12}  {:>12}  {:>12}  {:>12}  {:>12}",
+        "Day", "Read", "Parse", "A", "B", "Total"
+    );
+    for result in results {
+        let timing = &result.timing;
+        println!(
+            "{:>3}  {:>12}  {:>12}  {:>12}  {:>12}  {:>12}",
+            result.day,
+            format_duration(timing.read),
+            format_duration(timing.parse),
+            format_optional_duration(timing.part_a),
+            format_optional_duration(timing.part_b),
+            format_duration(timing.total()),
+        );
+    }
+
+    let total: Duration = results.iter().map(|result| result.timing.total()).sum();
+    println!("Total: {}", format_duration(total));
+
+    let mut slowest: Vec<&DayResult> = results.iter().collect();
+    slowest.sort_by_key(|result| std::cmp::Reverse(result.timing.total()));
+    println!(
+        "Slowest: {}",
+        slowest
+            .iter()
+            .take(SLOWEST_DAYS)
+            .map(|result| format!(
+                "day {} ({})",
+                result.day,
+                format_duration(result.timing.total())
+            ))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+}
+
+fn print_batch_table(results: &[DayResult], check: bool) {
+    // Each row is an input file name and either its answers, time and checks, or why it failed.
+    let rows: Vec<(String, Result<[String; 4], String>)> = results
+        .iter()
+        .map(|result| {
+            let input = Path::new(&result.input)
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| result.input.clone());
+            let cells = if let Some(timeout) = result.timed_out {
+                Err(format!("TIMEOUT after {}", format_duration(timeout)))
+            } else if let Some(error) = &result.error {
+                Err(format!("ERROR: {error}"))
+            } else {
+                Ok([
+                    result.answer_a.clone().unwrap_or_default(),
+                    result.answer_b.clone().unwrap_or_default(),
+                    format_duration(result.timing.total()),
+                    [("A", result.check_a()), ("B", result.check_b())]
+                        .into_iter()
+                        .filter_map(|(part, check)| Some(format!("{part} {}", check?)))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ])
+            };
+            (input, cells)
+        })
+        .collect();
+
+    let input_width = rows
+        .iter()
+        .map(|(input, _)| input.chars().count())
+        .chain(["Input".len()])
+        .max()
+        .unwrap_or(0);
+    let width = |column: usize, header: &str| {
+        rows.iter()
+            .filter_map(|(_, cells)| cells.as_ref().ok())
+            .flat_map(|cells| cells[column].lines())
+            .map(|line| line.chars().count())
+            .chain([header.len()])
+            .max()
+            .unwrap_or(0)
+    };
+    let (a_width, b_width, time_width) = (width(0, "A"), width(1, "B"), width(2, "Time"));
+    let print_row = |input: &str, a: &str, b: &str, time: &str, checks: &str| {
+        let line = format!(
+            "{input:<input_width$}  {a:<a_width$}  {b:<b_width$}  {time:>time_width$}  {}",
+            if check { checks } else { "" },
+        );
+        println!("{}", line.trim_end());
+    };
+
+    println!();
+    if let Some(result) = results.first() {
+        println!("=== Day {: >2}: {} inputs ===", result.day, results.len());
+    }
+    print_row("Input", "A", "B", "Time", "Check");
+    for (input, cells) in &rows {
+        match cells {
+            Ok([a, b, time, checks]) => {
+                // Multi-line answers continue on the following rows.
+                let a_lines: Vec<&str> = a.lines().collect();
+                let b_lines: Vec<&str> = b.lines().collect();
+                for i in 0..a_lines.len().max(b_lines.len()).max(1) {
+                    let (a, b) = (a_lines.get(i), b_lines.get(i));
+                    let (a, b) = (a.copied().unwrap_or(""), b.copied().unwrap_or(""));
+                    if i == 0 {
+                        print_row(input, a, b, time, checks);
+                    } else {
+                        print_row("", a, b, "", "");
+                    }
+                }
+            }
+            Err(failure) => println!("{input:<input_width$}  {failure}"),
+        }
+    }
+}
+
+fn print_memory_summary(results: &[DayResult]) {
+    println!();
+    println!("=== Memory ===");
+    println!(
+        "{:>3}  {:>12}  {:>12}  {:>12}",
+        "Day", "Peak", "Allocated", "Allocations"
+    );
+    for result in results {
+        if let Some(memory) = &result.memory {
+            println!(
+                "{:>3}  {:>12}  {:>12}  {:>12}",
+                result.day,
+                format_bytes(memory.peak),
+                format_bytes(memory.allocated),
+                memory.allocations,
+            );
+        }
+    }
+}
+
+/// Compare the wall time with the CPU time of all days, or with the sum of their wall times where
+/// CPU time can't be measured.
+fn print_wall_time(results: &[DayResult], wall_time: Duration) {
+    let cpu_time: Option<Duration> = results.iter().map(|result| result.cpu_time).sum();
+    let (label, days_time) = match cpu_time {
+        Some(cpu_time) => ("CPU time of all days", cpu_time),
+        None => (
+            "sum of day times",
+            results.iter().map(|result| result.timing.total()).sum(),
+        ),
+    };
+    println!();
+    println!(
+        "Wall time: {}, {label}: {} ({:.2}x speedup)",
+        format_duration(wall_time),
+        format_duration(days_time),
+        days_time.as_secs_f64() / wall_time.as_secs_f64()
+    );
+}
+
+fn print_check_summary(results: &[DayResult]) {
+    let count = |target: Check| {
+        results
+            .iter()
+            .flat_map(DayResult::checks)
+            .filter(|check| *check == target)
+            .count()
+    };
+    println!();
+    println!(
+        "Check: {} passed, {} failed, {} unknown",
+        count(Check::Pass),
+        count(Check::Fail),
+        count(Check::Unknown)
+    );
+}
+
+/// Milliseconds with microsecond precision, for machine-readable output.
+fn ms(duration: Duration) -> String {
+    format!("{:.3}", millis(duration))
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_string_escapes_special_characters() {
+        assert_eq!(json_string("1234"), "\"1234\"");
+        assert_eq!(json_string("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(json_string("C:\\inputs"), "\"C:\\\\inputs\"");
+        assert_eq!(json_string("###\n#.#\r\t"), "\"###\\n#.#\\r\\t\"");
+        assert_eq!(json_string("\u{0}\u{1b}"), "\"\\u0000\\u001b\"");
+        assert_eq!(json_string("√"), "\"√\"");
+    }
+
+    #[test]
+    fn csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("1234"), "1234");
+        assert_eq!(csv_field(""), "");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("###\n#.#"), "\"###\n#.#\"");
+        assert_eq!(csv_field("a\rb"), "\"a\rb\"");
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc;
+use std::sync::mpsc::RecvTimeoutError;
+use std::thread;
+use std::time::Duration;
+
+use adventofcode_2022::cancel;
+use adventofcode_2022::cancel::CancelToken;
+
+/// Same as the usual main thread stack size on Linux, since some days recurse deeply.
+const WORKER_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Apply `f` to each of `items` on `jobs` worker threads, and pass the results to `consume` in
+/// the same order as `items`. No new items are started once `consume` returns `false`.
+pub fn map_ordered<T, R, F, C>(items: &[T], jobs: usize, f: F, mut consume: C)
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+    C: FnMut(R) -> bool,
+{
+    if jobs <= 1 {
+        for item in items {
+            if !consume(f(item)) {
+                break;
+            }
+        }
+        return;
+    }
+
+    let next_item = AtomicUsize::new(0);
+    let stop = AtomicBool::new(false);
+    let (results_tx, results_rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let (f, next_item, stop, results_tx) = (&f, &next_item, &stop, results_tx.clone());
+            thread::Builder::new()
+                .stack_size(WORKER_STACK_SIZE)
+                .spawn_scoped(scope, move || {
+                    while !stop.load(Ordering::Relaxed) {
+                        let i = next_item.fetch_add(1, Ordering::Relaxed);
+                        match items.get(i) {
+                            Some(item) => {
+                                if results_tx.send((i, f(item))).is_err() {
+                                    break;
+                                }
+                            }
+                            None => break,
+                        }
+                    }
+                })
+                .expect("Failed to spawn worker thread");
+        }
+        drop(results_tx);
+
+        let mut pending = HashMap::new();
+        let mut next_result = 0;
+        for (i, result) in results_rx {
+            pending.insert(i, result);
+            while let Some(result) = pending.remove(&next_result) {
+                next_result += 1;
+                if !consume(result) {
+                    stop.store(true, Ordering::Relaxed);
+                    return;
+                }
+            }
+        }
+    });
+}
+
+/// Run `f` on a new thread and wait at most `timeout` for it to finish. On timeout, `f` is asked to
+/// stop through [cancel::is_cancelled] and `None` is returned right away; if `f` never checks for
+/// cancellation, it keeps running in the background until the program exits. A panic in `f` is
+/// propagated.
+pub fn run_with_timeout<T, F>(timeout: Duration, f: F) -> Option<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let token = CancelToken::new();
+    let thread_token = token.clone();
+    let (result_tx, result_rx) = mpsc::channel();
+    let handle = thread::Builder::new()
+        .stack_size(WORKER_STACK_SIZE)
+        .spawn(move || {
+            cancel::set_current(thread_token);
+            let _ = result_tx.send(f());
+        })
+        .expect("Failed to spawn worker thread");
+
+    match result_rx.recv_timeout(timeout) {
+        Ok(result) => Some(result),
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            None
+        }
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(panic) => std::panic::resume_unwind(panic),
+            Ok(()) => unreachable!("Worker thread finished without a result"),
+        },
+    }
+}
@@ -0,0 +1,92 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::timing::format_duration;
+
+/// Width of the bar of the slowest day, in characters.
+const BAR_WIDTH: usize = 20;
+
+/// Characters for the last, partially filled character of a bar, by eighths filled.
+const PARTIAL_BLOCKS: [&str; 8] = ["", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+
+/// The answers and run time of one day.
+pub struct DayReport {
+    pub day: u8,
+    pub answer_a: String,
+    pub answer_b: String,
+    /// Median time to parse the input and solve both parts.
+    pub median: Duration,
+}
+
+/// Render a Markdown document with a table of each day's answers, median time and a bar showing
+/// its time relative to the slowest day. Multi-line answers such as CRT art don't fit in a table
+/// cell, so they follow the table in code blocks.
+pub fn render(year: u16, iterations: usize, reports: &[DayReport]) -> String {
+    let slowest = reports
+        .iter()
+        .map(|report| report.median)
+        .max()
+        .unwrap_or_default();
+    let total: Duration = reports.iter().map(|report| report.median).sum();
+
+    let mut out = String::new();
+    writeln!(out, "# Advent of Code {year}").unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "Median time of {iterations} run(s) to parse the input and solve both parts."
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "| Day | A | B | Time | Relative time |").unwrap();
+    writeln!(out, "|----:|---|---|-----:|---------------|").unwrap();
+    let mut code_blocks = Vec::new();
+    for report in reports {
+        let mut cell = |part: &str, answer: &str| {
+            let answer = answer.trim_matches('\n');
+            if answer.contains('\n') {
+                let heading = format!("Day {} part {part}", report.day);
+                let cell = format!("[see below](#{})", heading.to_lowercase().replace(' ', "-"));
+                code_blocks.push((heading, answer.to_string()));
+                cell
+            } else if answer.is_empty() {
+                String::new()
+            } else {
+                format!("`{answer}`")
+            }
+        };
+        let (a, b) = (cell("A", &report.answer_a), cell("B", &report.answer_b));
+        writeln!(
+            out,
+            "| {} | {a} | {b} | {} | {} |",
+            report.day,
+            format_duration(report.median),
+            bar(report.median, slowest)
+        )
+        .unwrap();
+    }
+    writeln!(out, "| **Total** | | | {} | |", format_duration(total)).unwrap();
+
+    for (heading, answer) in code_blocks {
+        writeln!(out).unwrap();
+        writeln!(out, "### {heading}").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "```").unwrap();
+        writeln!(out, "{answer}").unwrap();
+        writeln!(out, "```").unwrap();
+    }
+    out
+}
+
+/// A bar of block characters proportional to `time`, [BAR_WIDTH] characters long for `slowest`.
+/// Every bar is at least one eighth of a character long, so that no day looks like it took no
+/// time at all.
+fn bar(time: Duration, slowest: Duration) -> String {
+    let fraction = if slowest.is_zero() {
+        0.0
+    } else {
+        time.as_secs_f64() / slowest.as_secs_f64()
+    };
+    let eighths = ((fraction * (BAR_WIDTH * 8) as f64).round() as usize).max(1);
+    "█".repeat(eighths / 8) + PARTIAL_BLOCKS[eighths % 8]
+}
@@ -0,0 +1,72 @@
+use std::io::ErrorKind;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use adventofcode_2022::error::Error;
+
+const DAYS_DIR: &str = "src/days";
+
+const TEMPLATE: &str = r#"use crate::common::Day;
+use crate::common::Example;
+use crate::common::Params;
+use crate::error::ParseError;
+use crate::util::parse;
+
+pub struct DayNN;
+
+impl Day for DayNN {
+    type Input<'a> = Vec<&'a str>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("examples/dayNN.in"),
+        answer_a: None,
+        answer_b: None,
+        params: &[],
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(parse::nonempty_lines(input).map(|line| line.rest()).collect())
+    }
+
+    fn part_a(input: &Self::Input<'_>, _: &Params) -> String {
+        input.len().to_string()
+    }
+
+    fn part_b(_input: &Self::Input<'_>, _: &Params) -> String {
+        "".to_string()
+    }
+}
+"#;
+
+/// Create `src/days/<year>/dayNN.rs` from a template, and an empty example input
+/// `src/days/<year>/examples/dayNN.in`. The build script registers the new module with the runner,
+/// tests and benchmarks, and the year too if this is its first day.
+pub fn new_day(year: u16, day: u8) -> Result<Vec<PathBuf>, Error> {
+    let days_dir = Path::new(DAYS_DIR);
+    if !days_dir.is_dir() {
+        return Err(Error::NotInRepository(days_dir.to_path_buf()));
+    }
+    let days_dir = days_dir.join(year.to_string());
+    std::fs::create_dir_all(&days_dir)?;
+    let module_path = days_dir.join(format!("day{day:02}.rs"));
+    let example_path = days_dir.join("examples").join(format!("day{day:02}.in"));
+    create_new(&module_path, &TEMPLATE.replace("NN", &format!("{day:02}")))?;
+    std::fs::create_dir_all(days_dir.join("examples"))?;
+    if !example_path.exists() {
+        create_new(&example_path, "")?;
+    }
+    Ok(vec![module_path, example_path])
+}
+
+fn create_new(path: &Path, contents: &str) -> Result<(), Error> {
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map_err(|err| match err.kind() {
+            ErrorKind::AlreadyExists => Error::DayExists(path.to_path_buf()),
+            _ => Error::Io(err),
+        })?;
+    Ok(file.write_all(contents.as_bytes())?)
+}
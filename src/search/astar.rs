@@ -2,10 +2,15 @@ use std::collections::hash_map::Entry;
 use std::collections::BinaryHeap;
 use std::collections::HashMap;
 
+use crate::cancel;
+use crate::search::stats;
+use crate::search::stats::SearchStats;
+
 pub trait State
 where
     Self: PartialEq,
     Self: Eq,
+    Self: std::fmt::Debug,
 {
     type DuplicationKey: Eq + std::hash::Hash;
     type Value: Ord;
@@ -21,12 +26,23 @@ where
     }
 }
 
-#[derive(Eq, PartialEq)]
-struct StateOrd<S>(S)
+/// A queued state, ordered by estimate, along with what [Parents] knows it by.
+struct StateOrd<S, I>(S, I)
 where
     S: State;
 
-impl<S> PartialOrd for StateOrd<S>
+impl<S, I> PartialEq for StateOrd<S, I>
+where
+    S: State,
+{
+    fn eq(&self, rhs: &Self) -> bool {
+        self.0 == rhs.0
+    }
+}
+
+impl<S, I> Eq for StateOrd<S, I> where S: State {}
+
+impl<S, I> PartialOrd for StateOrd<S, I>
 where
     S: State,
 {
@@ -35,92 +51,389 @@ where
     }
 }
 
-impl<S> Ord for StateOrd<S>
+impl<S, I> Ord for StateOrd<S, I>
 where
     S: State,
-    S: PartialEq,
-    S: Eq,
 {
     fn cmp(&self, rhs: &Self) -> std::cmp::Ordering {
         rhs.0.estimate().cmp(&self.0.estimate())
     }
 }
 
+/// Keeps track of which state each state was generated from, so that the path to a state can be
+/// reconstructed.
+trait Parents<S> {
+    type Id: Copy;
+
+    /// Remember `state`, generated from the state recorded as `parent`, or the initial state if
+    /// `parent` is `None`.
+    fn record(&mut self, state: &S, parent: Option<Self::Id>) -> Self::Id;
+
+    /// The states from the initial state to the state recorded as `last`, inclusive.
+    fn path(&self, last: Self::Id) -> Vec<S>;
+}
+
+/// Remembers nothing, so that searches without paths use no extra time or memory.
+struct NoParents;
+
+impl<S> Parents<S> for NoParents {
+    type Id = ();
+
+    fn record(&mut self, _: &S, _: Option<()>) {}
+
+    fn path(&self, _: ()) -> Vec<S> {
+        Vec::new()
+    }
+}
+
+/// Keeps a copy of every generated state and the index of its parent.
+struct StateTree<S> {
+    nodes: Vec<(S, Option<usize>)>,
+}
+
+impl<S> Parents<S> for StateTree<S>
+where
+    S: Clone,
+{
+    type Id = usize;
+
+    fn record(&mut self, state: &S, parent: Option<usize>) -> usize {
+        self.nodes.push((state.clone(), parent));
+        self.nodes.len() - 1
+    }
+
+    fn path(&self, last: usize) -> Vec<S> {
+        let mut path: Vec<S> = std::iter::successors(Some(last), |&id| self.nodes[id].1)
+            .map(|id| self.nodes[id].0.clone())
+            .collect();
+        path.reverse();
+        path
+    }
+}
+
 pub fn astar<S>(initial_state: S) -> Option<S>
 where
     S: State,
 {
-    let mut queue: BinaryHeap<StateOrd<S>> = BinaryHeap::new();
+    astar_with(initial_state, &mut NoParents).map(|(state, ())| state)
+}
+
+/// Same as [astar], but returns every state on the way from `initial_state` to the finished
+/// state, inclusive. This keeps a copy of every generated state, so it uses a lot more memory.
+pub fn astar_path<S>(initial_state: S) -> Option<Vec<S>>
+where
+    S: State,
+    S: Clone,
+{
+    let mut tree = StateTree { nodes: Vec::new() };
+    astar_with(initial_state, &mut tree).map(|(_, last)| tree.path(last))
+}
+
+fn astar_with<S, P>(initial_state: S, parents: &mut P) -> Option<(S, P::Id)>
+where
+    S: State,
+    P: Parents<S>,
+{
+    stats::record(|stats| {
+        if stats::instrumented() {
+            astar_search::<S, P, true>(initial_state, parents, stats)
+        } else {
+            astar_search::<S, P, false>(initial_state, parents, stats)
+        }
+    })
+}
+
+/// Counts and traces what the search does only if `INSTRUMENTED` is set.
+fn astar_search<S, P, const INSTRUMENTED: bool>(
+    initial_state: S,
+    parents: &mut P,
+    stats: &mut SearchStats,
+) -> Option<(S, P::Id)>
+where
+    S: State,
+    P: Parents<S>,
+{
+    let mut queue: BinaryHeap<StateOrd<S, P::Id>> = BinaryHeap::new();
     let mut visited: HashMap<S::DuplicationKey, S::Value> = HashMap::new();
 
-    queue.push(StateOrd(initial_state));
+    let root = parents.record(&initial_state, None);
+    push::<_, _, INSTRUMENTED>(&mut queue, StateOrd(initial_state, root), stats);
 
-    while let Some(StateOrd(state)) = queue.pop() {
-        if state.finished() {
-            return Some(state);
+    while let Some(StateOrd(state, id)) = queue.pop() {
+        if INSTRUMENTED {
+            stats.popped += 1;
+        }
+        if cancel::is_cancelled() {
+            return None;
+        } else if state.finished() {
+            return Some((state, id));
         } else if visited
             .get(&state.duplication_key())
             .map(|v| state.value() <= *v)
             .unwrap_or(true)
         {
+            if INSTRUMENTED {
+                stats::trace(stats, &state);
+            }
             for next_state in state.generate_moves() {
                 let dk = next_state.duplication_key();
                 let nv = next_state.value();
                 match visited.entry(dk) {
                     Entry::Occupied(mut occ) if nv < *occ.get() => {
                         occ.insert(nv);
-                        queue.push(StateOrd(next_state));
+                        let next_id = parents.record(&next_state, Some(id));
+                        push::<_, _, INSTRUMENTED>(
+                            &mut queue,
+                            StateOrd(next_state, next_id),
+                            stats,
+                        );
                     }
                     Entry::Vacant(vac) => {
                         vac.insert(nv);
-                        queue.push(StateOrd(next_state));
+                        let next_id = parents.record(&next_state, Some(id));
+                        push::<_, _, INSTRUMENTED>(
+                            &mut queue,
+                            StateOrd(next_state, next_id),
+                            stats,
+                        );
                     }
+                    _ if INSTRUMENTED => stats.pruned += 1,
                     _ => {}
                 }
             }
+        } else if INSTRUMENTED {
+            stats.pruned += 1;
         }
     }
 
     None
 }
 
+fn push<S, I, const INSTRUMENTED: bool>(
+    queue: &mut BinaryHeap<StateOrd<S, I>>,
+    state: StateOrd<S, I>,
+    stats: &mut SearchStats,
+) where
+    S: State,
+{
+    queue.push(state);
+    if INSTRUMENTED {
+        stats.pushed += 1;
+        stats.peak_queue = stats.peak_queue.max(queue.len());
+    }
+}
+
 pub fn astar_optimize<S>(initial_state: S) -> S::Value
 where
     S: State,
     S::Value: Clone,
 {
-    let mut queue: BinaryHeap<StateOrd<S>> = BinaryHeap::new();
+    astar_optimize_with(initial_state, &mut NoParents, |_| {}).0
+}
+
+/// Same as [astar_optimize], but also returns a state with the best value. This clones each state
+/// that improves on the best value so far, but unlike [astar_optimize_path] keeps no other states.
+pub fn astar_optimize_state<S>(initial_state: S) -> (S::Value, S)
+where
+    S: State,
+    S: Clone,
+    S::Value: Clone,
+{
+    let mut best_state = initial_state.clone();
+    let (best, ()) = astar_optimize_with(initial_state, &mut NoParents, |state: &S| {
+        best_state = state.clone()
+    });
+    (best, best_state)
+}
+
+/// Same as [astar_optimize], but also returns every state on the way from `initial_state` to a
+/// state with the best value, inclusive. This keeps a copy of every generated state, so it uses a
+/// lot more memory.
+pub fn astar_optimize_path<S>(initial_state: S) -> (S::Value, Vec<S>)
+where
+    S: State,
+    S: Clone,
+    S::Value: Clone,
+{
+    let mut tree = StateTree { nodes: Vec::new() };
+    let (best, best_id) = astar_optimize_with(initial_state, &mut tree, |_| {});
+    (best, tree.path(best_id))
+}
+
+/// Run [astar_optimize], calling `improved` with each state that improves on the best value so
+/// far.
+fn astar_optimize_with<S, P, F>(initial_state: S, parents: &mut P, improved: F) -> (S::Value, P::Id)
+where
+    S: State,
+    S::Value: Clone,
+    P: Parents<S>,
+    F: FnMut(&S),
+{
+    stats::record(|stats| {
+        if stats::instrumented() {
+            astar_optimize_search::<S, P, F, true>(initial_state, parents, improved, stats)
+        } else {
+            astar_optimize_search::<S, P, F, false>(initial_state, parents, improved, stats)
+        }
+    })
+}
+
+/// Counts and traces what the search does only if `INSTRUMENTED` is set.
+fn astar_optimize_search<S, P, F, const INSTRUMENTED: bool>(
+    initial_state: S,
+    parents: &mut P,
+    mut improved: F,
+    stats: &mut SearchStats,
+) -> (S::Value, P::Id)
+where
+    S: State,
+    S::Value: Clone,
+    P: Parents<S>,
+    F: FnMut(&S),
+{
+    let mut queue: BinaryHeap<StateOrd<S, P::Id>> = BinaryHeap::new();
     let mut visited: HashMap<S::DuplicationKey, S::Value> = HashMap::new();
     let mut best = initial_state.value();
+    let mut best_id = parents.record(&initial_state, None);
 
-    queue.push(StateOrd(initial_state));
+    push::<_, _, INSTRUMENTED>(&mut queue, StateOrd(initial_state, best_id), stats);
 
-    while let Some(StateOrd(state)) = queue.pop() {
-        if state.estimate() > best {
-            return best;
+    while let Some(StateOrd(state, id)) = queue.pop() {
+        if INSTRUMENTED {
+            stats.popped += 1;
+        }
+        if state.estimate() > best || cancel::is_cancelled() {
+            return (best, best_id);
         } else if visited
             .get(&state.duplication_key())
             .map(|v| state.value() <= *v)
             .unwrap_or(true)
         {
+            if INSTRUMENTED {
+                stats::trace(stats, &state);
+            }
             for next_state in state.generate_moves() {
                 let dk = next_state.duplication_key();
                 let nv = next_state.value();
-                best = std::cmp::min(best, nv.clone());
+                let next_id = parents.record(&next_state, Some(id));
+                if nv < best {
+                    best = nv.clone();
+                    best_id = next_id;
+                    improved(&next_state);
+                }
                 match visited.entry(dk) {
                     Entry::Occupied(mut occ) if nv < *occ.get() => {
                         occ.insert(nv);
-                        queue.push(StateOrd(next_state));
+                        push::<_, _, INSTRUMENTED>(
+                            &mut queue,
+                            StateOrd(next_state, next_id),
+                            stats,
+                        );
                     }
                     Entry::Vacant(vac) => {
                         vac.insert(nv);
-                        queue.push(StateOrd(next_state));
+                        push::<_, _, INSTRUMENTED>(
+                            &mut queue,
+                            StateOrd(next_state, next_id),
+                            stats,
+                        );
                     }
+                    _ if INSTRUMENTED => stats.pruned += 1,
                     _ => {}
                 }
             }
+        } else if INSTRUMENTED {
+            stats.pruned += 1;
         }
     }
 
-    best
+    (best, best_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A walk along a number line from 0 to `TARGET`, in steps of 1 or 3 that each cost 1, except
+    /// that 3 is a pit that costs 10 to step on.
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    struct Walk {
+        pos: i32,
+        cost: u32,
+    }
+
+    const TARGET: i32 = 7;
+
+    impl State for Walk {
+        type DuplicationKey = i32;
+        type Value = u32;
+        type NewStates = std::vec::IntoIter<Self>;
+
+        fn value(&self) -> u32 {
+            self.cost
+        }
+
+        fn estimate(&self) -> u32 {
+            self.cost + (TARGET - self.pos).unsigned_abs().div_ceil(3)
+        }
+
+        fn duplication_key(&self) -> i32 {
+            self.pos
+        }
+
+        fn generate_moves(self) -> Self::NewStates {
+            [1, 3]
+                .into_iter()
+                .map(|step| self.pos + step)
+                .filter(|pos| *pos <= TARGET)
+                .map(|pos| Walk {
+                    pos,
+                    cost: self.cost + if pos == 3 { 10 } else { 1 },
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+        }
+    }
+
+    #[test]
+    fn path_avoids_pit() {
+        let start = Walk { pos: 0, cost: 0 };
+        let path = astar_path(start.clone()).unwrap();
+        let positions: Vec<i32> = path.iter().map(|walk| walk.pos).collect();
+        assert_eq!(positions, vec![0, 1, 4, 7]);
+        assert_eq!(path.last(), astar(start).as_ref());
+    }
+
+    #[test]
+    fn optimize_state_matches_path() {
+        let start = Walk { pos: 0, cost: 0 };
+        let (value, state) = astar_optimize_state(start.clone());
+        let (path_value, path) = astar_optimize_path(start.clone());
+        assert_eq!(value, astar_optimize(start));
+        assert_eq!(value, path_value);
+        assert_eq!(Some(&state), path.last());
+        assert_eq!(state.cost, value);
+    }
+
+    #[test]
+    fn collects_stats_and_traces_expanded_states() {
+        let expanded = std::rc::Rc::new(std::cell::Cell::new(0));
+        let traced = expanded.clone();
+        let (end, stats) = stats::collect(|| {
+            stats::traced(
+                move |trace| {
+                    if let stats::Trace::Expanded(..) = trace {
+                        traced.set(traced.get() + 1)
+                    }
+                },
+                || astar(Walk { pos: 0, cost: 0 }),
+            )
+        });
+        assert_eq!(end.map(|walk| walk.cost), Some(3));
+        assert_eq!(stats.searches, 1);
+        assert!(expanded.get() > 0);
+        assert!(stats.popped > expanded.get());
+        assert!(stats.pushed >= stats.popped);
+        assert!(stats.peak_queue > 0);
+    }
 }
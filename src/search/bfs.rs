@@ -0,0 +1,77 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use super::Distances;
+
+/// Breadth-first search from all of `starts` at once, where `neighbors(node)` gives the nodes one
+/// step away from `node`. The search stops at the first node for which `is_goal` is true, or when
+/// there are no more nodes to reach.
+pub fn bfs<N, I, F, G>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: F,
+    mut is_goal: G,
+) -> Distances<N, usize>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(vac) = distances.entry(start.clone()) {
+            vac.insert(0);
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, distance)) = queue.pop_front() {
+        if is_goal(&node) {
+            return Distances {
+                distances,
+                goal: Some(node),
+            };
+        }
+        for next in neighbors(&node) {
+            if let Entry::Vacant(vac) = distances.entry(next.clone()) {
+                vac.insert(distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    Distances {
+        distances,
+        goal: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Nodes 0 to 9 in a line.
+    fn line(&node: &i32) -> impl Iterator<Item = i32> {
+        [node - 1, node + 1]
+            .into_iter()
+            .filter(|next| (0..10).contains(next))
+    }
+
+    #[test]
+    fn stops_at_goal_nearest_any_start() {
+        let result = bfs([0, 9], line, |node| *node == 6);
+        assert_eq!(result.goal, Some(6));
+        assert_eq!(result.goal_distance(), Some(&3));
+        assert!(!result.distances.contains_key(&5));
+
+        let all = bfs([0, 9], line, |_| false);
+        assert_eq!(all.goal, None);
+        let expected: HashMap<i32, usize> = (0..10)
+            .map(|node| (node, std::cmp::min(node, 9 - node) as usize))
+            .collect();
+        assert_eq!(all.distances, expected);
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::HashSet;
+
+use crate::cancel;
+use crate::search::stats;
+use crate::search::stats::SearchStats;
+
+/// A state in a depth-first search for the highest score. Branches are cut off as soon as their
+/// upper bound shows that they can't beat the best score found so far.
+pub trait State
+where
+    Self: Sized,
+    Self: std::fmt::Debug,
+{
+    type Score: Ord + Copy;
+    type MemoKey: Eq + std::hash::Hash;
+    type NewStates<'a>: Iterator<Item = Self>
+    where
+        Self: 'a;
+
+    /// The score of stopping in this state.
+    fn score(&self) -> Self::Score;
+
+    /// A score at least as high as that of this state and of every state reachable from it.
+    fn upper_bound(&self) -> Self::Score;
+
+    /// The states reachable in one move. Unlike [astar::State::generate_moves], this borrows the
+    /// state, which stays on the stack while its moves are searched.
+    ///
+    /// [astar::State::generate_moves]: super::astar::State::generate_moves
+    fn generate_moves(&self) -> Self::NewStates<'_>;
+
+    /// A key shared only by states from which the same scores are reachable. States with a key
+    /// that has already been searched are skipped. By default nothing is memoized.
+    fn memo_key(&self) -> Option<Self::MemoKey> {
+        None
+    }
+
+    /// Whether to sort each state's moves with [State::order_moves] before searching them.
+    /// Otherwise moves are searched one at a time in the order they are generated, which is
+    /// faster when generating them is cheap.
+    const ORDER_MOVES: bool = false;
+
+    /// Sort moves into the order to search them in. Searching the most promising moves first
+    /// finds high scores sooner, so that more branches are cut off.
+    fn order_moves(_moves: &mut [Self]) {}
+}
+
+/// The highest score of `initial_state` or any state reachable from it.
+pub fn branch_and_bound<S>(initial_state: S) -> S::Score
+where
+    S: State,
+{
+    stats::record(|stats| {
+        if stats::instrumented() {
+            Search::<S, true>::run(initial_state, stats)
+        } else {
+            Search::<S, false>::run(initial_state, stats)
+        }
+    })
+}
+
+/// A running search, which only counts and traces what it does if `INSTRUMENTED` is set.
+struct Search<S, const INSTRUMENTED: bool>
+where
+    S: State,
+{
+    /// Memo keys of states that have been searched.
+    searched: HashSet<S::MemoKey>,
+    /// Empty vectors left by finished levels of the recursion, to hold the moves of the next ones
+    /// without allocating.
+    buffers: Vec<Vec<S>>,
+    /// States being searched, and moves sorted but not yet searched or cut off, on every level of
+    /// the recursion.
+    pending: usize,
+    stats: SearchStats,
+}
+
+impl<S, const INSTRUMENTED: bool> Search<S, INSTRUMENTED>
+where
+    S: State,
+{
+    fn run(initial_state: S, stats: &mut SearchStats) -> S::Score {
+        let mut search = Self {
+            searched: HashSet::new(),
+            buffers: Vec::new(),
+            pending: 0,
+            stats: *stats,
+        };
+        if let Some(key) = initial_state.memo_key() {
+            search.searched.insert(key);
+        }
+        let score = initial_state.score();
+        let best = search.recurse(initial_state, score);
+        *stats = search.stats;
+        best
+    }
+
+    /// Search the states reachable from `state`. Returns the best score found so far, which is at
+    /// least `best`.
+    fn recurse(&mut self, state: S, mut best: S::Score) -> S::Score {
+        if INSTRUMENTED {
+            self.stats.popped += 1;
+            self.pending += 1;
+            self.stats.peak_queue = self.stats.peak_queue.max(self.pending);
+            stats::trace(&self.stats, &state);
+        }
+        if cancel::is_cancelled() {
+            return best;
+        }
+
+        if S::ORDER_MOVES {
+            let mut moves = self.buffers.pop().unwrap_or_default();
+            moves.extend(state.generate_moves());
+            S::order_moves(&mut moves);
+            if INSTRUMENTED {
+                self.pending += moves.len();
+                self.stats.pushed += moves.len();
+                self.stats.peak_queue = self.stats.peak_queue.max(self.pending);
+            }
+            for next_state in moves.drain(..) {
+                if INSTRUMENTED {
+                    self.pending -= 1;
+                }
+                best = self.visit(next_state, best);
+            }
+            self.buffers.push(moves);
+        } else {
+            for next_state in state.generate_moves() {
+                if INSTRUMENTED {
+                    self.stats.pushed += 1;
+                }
+                best = self.visit(next_state, best);
+            }
+        }
+
+        if INSTRUMENTED {
+            self.pending -= 1;
+        }
+        best
+    }
+
+    /// Search `state` unless it is cut off. Returns the best score found so far, which is at least
+    /// `best`.
+    fn visit(&mut self, state: S, best: S::Score) -> S::Score {
+        if state.upper_bound() <= best || !self.first_visit(&state) {
+            if INSTRUMENTED {
+                self.stats.pruned += 1;
+            }
+            best
+        } else {
+            let best = std::cmp::max(best, state.score());
+            self.recurse(state, best)
+        }
+    }
+
+    /// Whether `state` has no memo key or is the first state searched with its memo key.
+    fn first_visit(&mut self, state: &S) -> bool {
+        state
+            .memo_key()
+            .map(|key| self.searched.insert(key))
+            .unwrap_or(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Choosing items with the given weights and values to put in a knapsack that holds at most
+    /// `CAPACITY`, one item at a time in order.
+    #[derive(Clone, Copy, Debug)]
+    struct Knapsack {
+        next: usize,
+        weight: u32,
+        value: u32,
+    }
+
+    const ITEMS: [(u32, u32); 5] = [(5, 10), (4, 40), (6, 30), (3, 50), (2, 15)];
+    const CAPACITY: u32 = 10;
+
+    impl State for Knapsack {
+        type Score = u32;
+        type MemoKey = (usize, u32, u32);
+        type NewStates<'a> = std::vec::IntoIter<Self>;
+
+        fn score(&self) -> u32 {
+            self.value
+        }
+
+        fn upper_bound(&self) -> u32 {
+            self.value + ITEMS[self.next..].iter().map(|(_, v)| v).sum::<u32>()
+        }
+
+        fn generate_moves(&self) -> Self::NewStates<'_> {
+            let mut moves = Vec::new();
+            if let Some((w, v)) = ITEMS.get(self.next) {
+                moves.push(Knapsack {
+                    next: self.next + 1,
+                    ..*self
+                });
+                if self.weight + w <= CAPACITY {
+                    moves.push(Knapsack {
+                        next: self.next + 1,
+                        weight: self.weight + w,
+                        value: self.value + v,
+                    });
+                }
+            }
+            moves.into_iter()
+        }
+
+        fn memo_key(&self) -> Option<(usize, u32, u32)> {
+            Some((self.next, self.weight, self.value))
+        }
+
+        const ORDER_MOVES: bool = true;
+
+        fn order_moves(moves: &mut [Self]) {
+            moves.sort_by_key(|state| std::cmp::Reverse(state.value));
+        }
+    }
+
+    #[test]
+    fn finds_best_knapsack() {
+        let (best, stats) = stats::collect(|| {
+            branch_and_bound(Knapsack {
+                next: 0,
+                weight: 0,
+                value: 0,
+            })
+        });
+        assert_eq!(best, 105);
+        assert_eq!(stats.searches, 1);
+        assert!(stats.pruned > 0);
+        assert_eq!(stats.pushed, stats.popped - 1 + stats.pruned);
+    }
+}
@@ -0,0 +1,106 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Add;
+
+use super::Distances;
+
+/// A queued node, ordered by its distance only.
+struct Queued<N, D>(D, N);
+
+impl<N, D: PartialEq> PartialEq for Queued<N, D> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.0 == rhs.0
+    }
+}
+
+impl<N, D: Eq> Eq for Queued<N, D> {}
+
+impl<N, D: Ord> PartialOrd for Queued<N, D> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl<N, D: Ord> Ord for Queued<N, D> {
+    fn cmp(&self, rhs: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&rhs.0)
+    }
+}
+
+/// Shortest path search from all of `starts` at once, where `neighbors(node)` gives the nodes
+/// next to `node` and the cost of moving to each of them. Costs must not be negative, and
+/// `D::default()` must be zero. The search stops at the first node for which `is_goal` is true, or
+/// when there are no more nodes to reach.
+pub fn dijkstra<N, D, I, F, G>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: F,
+    mut is_goal: G,
+) -> Distances<N, D>
+where
+    N: Clone + Eq + Hash,
+    D: Add<Output = D> + Copy + Default + Ord,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, D)>,
+    G: FnMut(&N) -> bool,
+{
+    let mut distances = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        distances.insert(start.clone(), D::default());
+        queue.push(Reverse(Queued(D::default(), start)));
+    }
+
+    while let Some(Reverse(Queued(distance, node))) = queue.pop() {
+        if distances.get(&node).map(|d| distance > *d).unwrap_or(false) {
+            // Already reached by a shorter path.
+            continue;
+        }
+        if is_goal(&node) {
+            return Distances {
+                distances,
+                goal: Some(node),
+            };
+        }
+        for (next, cost) in neighbors(&node) {
+            let next_distance = distance + cost;
+            if distances
+                .get(&next)
+                .map(|d| next_distance < *d)
+                .unwrap_or(true)
+            {
+                distances.insert(next.clone(), next_distance);
+                queue.push(Reverse(Queued(next_distance, next)));
+            }
+        }
+    }
+
+    Distances {
+        distances,
+        goal: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn takes_cheaper_longer_path() {
+        // 0 -> 1 costs 10, but 0 -> 2 -> 3 -> 1 costs 3.
+        let edges: [&[(u8, u32)]; 4] = [&[(1, 10), (2, 1)], &[], &[(3, 1)], &[(1, 1)]];
+        let result = dijkstra(
+            [0],
+            |node| edges[*node as usize].to_vec(),
+            |node| *node == 1,
+        );
+        assert_eq!(result.goal, Some(1));
+        assert_eq!(result.goal_distance(), Some(&3));
+
+        let all = dijkstra([0], |node| edges[*node as usize].to_vec(), |_| false);
+        assert_eq!(all.goal, None);
+        assert_eq!(all.distances.len(), 4);
+        assert_eq!(all.distances[&3], 2);
+    }
+}
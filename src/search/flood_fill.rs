@@ -0,0 +1,49 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// All nodes reachable from any of `starts`, including the starts, where `neighbors(node)` gives
+/// the nodes next to `node`. Unlike [super::bfs::bfs], this keeps no distances and visits nodes in
+/// no particular order.
+pub fn flood_fill<N, I, F>(starts: impl IntoIterator<Item = N>, mut neighbors: F) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut reached = HashSet::new();
+    let mut frontier = Vec::new();
+    for start in starts {
+        if reached.insert(start.clone()) {
+            frontier.push(start);
+        }
+    }
+
+    while let Some(node) = frontier.pop() {
+        for next in neighbors(&node) {
+            if reached.insert(next.clone()) {
+                frontier.push(next);
+            }
+        }
+    }
+
+    reached
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Nodes 0 to 9 in a line, with no edge between 4 and 5.
+    fn split_line(&node: &i32) -> impl Iterator<Item = i32> {
+        [node - 1, node + 1]
+            .into_iter()
+            .filter(move |next| (0..10).contains(next) && (*next < 5) == (node < 5))
+    }
+
+    #[test]
+    fn fills_from_every_start() {
+        assert_eq!(flood_fill([1], split_line), (0..5).collect());
+        assert_eq!(flood_fill([1, 8, 3], split_line), (0..10).collect());
+        assert_eq!(flood_fill([], split_line), HashSet::new());
+    }
+}
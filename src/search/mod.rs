@@ -1 +1,28 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
 pub mod astar;
+pub mod bfs;
+pub mod branch_and_bound;
+pub mod dijkstra;
+pub mod flood_fill;
+pub mod stats;
+
+/// The result of a [bfs::bfs] or [dijkstra::dijkstra] search.
+pub struct Distances<N, D> {
+    /// The distance to each node reached, from the nearest start. If the search stopped at a
+    /// goal, nodes farther away than the goal may be missing or have too long distances.
+    pub distances: HashMap<N, D>,
+    /// The first goal reached, which is one of the nearest goals, if any goal was reached.
+    pub goal: Option<N>,
+}
+
+impl<N, D> Distances<N, D>
+where
+    N: Eq + Hash,
+{
+    /// The distance to [Self::goal], if a goal was reached.
+    pub fn goal_distance(&self) -> Option<&D> {
+        self.distances.get(self.goal.as_ref()?)
+    }
+}
@@ -0,0 +1,123 @@
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Counts of what searches did, summed over all searches run while collecting.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SearchStats {
+    pub searches: usize,
+    /// States taken off the queue, or searched by a depth-first search.
+    pub popped: usize,
+    /// States skipped because the visited map had already seen a state with the same duplication
+    /// key and a better value, whether when they were generated or when they were popped. In a
+    /// depth-first search, states skipped because of their upper bound or memo key.
+    pub pruned: usize,
+    /// States added to the queue, or generated by a depth-first search.
+    pub pushed: usize,
+    /// The most states in the queue at once, in any one search. In a depth-first search, the most
+    /// states on the stack.
+    pub peak_queue: usize,
+    pub elapsed: Duration,
+}
+
+impl SearchStats {
+    fn add(&mut self, other: &Self) {
+        self.searches += other.searches;
+        self.popped += other.popped;
+        self.pruned += other.pruned;
+        self.pushed += other.pushed;
+        self.peak_queue = self.peak_queue.max(other.peak_queue);
+        self.elapsed += other.elapsed;
+    }
+}
+
+/// What the trace hook is called with.
+pub enum Trace<'a> {
+    /// A state that is about to be expanded, with the statistics of its search so far.
+    Expanded(&'a SearchStats, &'a dyn Debug),
+    /// The plan that a day chose from what its searches found, for days that can describe one.
+    Solution(&'a dyn Debug),
+}
+
+type TraceHook = Box<dyn FnMut(Trace)>;
+
+thread_local! {
+    static COLLECTED: RefCell<Option<SearchStats>> = const { RefCell::new(None) };
+    static TRACE_HOOK: RefCell<Option<TraceHook>> = const { RefCell::new(None) };
+}
+
+/// Run `f` and return its result along with the statistics of all searches it ran on the current
+/// thread.
+pub fn collect<T, F: FnOnce() -> T>(f: F) -> (T, SearchStats) {
+    let outer = COLLECTED.with(|collected| collected.replace(Some(SearchStats::default())));
+    let result = f();
+    let stats = COLLECTED.with(|collected| collected.replace(outer).unwrap_or_default());
+    (result, stats)
+}
+
+/// Run `f`, calling `hook` with each state expanded by searches on the current thread, along with
+/// the statistics of the search so far, and with the solutions that days report. The hook can log
+/// every state, or sample them by looking at [SearchStats::popped].
+pub fn traced<T, F, H>(hook: H, f: F) -> T
+where
+    F: FnOnce() -> T,
+    H: FnMut(Trace) + 'static,
+{
+    let outer = TRACE_HOOK.with(|trace_hook| trace_hook.replace(Some(Box::new(hook))));
+    let result = f();
+    TRACE_HOOK.with(|trace_hook| trace_hook.replace(outer));
+    result
+}
+
+/// Run one search, which updates the statistics it is given, and add them to those being
+/// collected on the current thread, if any.
+pub(crate) fn record<T, F: FnOnce(&mut SearchStats) -> T>(search: F) -> T {
+    let mut stats = SearchStats {
+        searches: 1,
+        ..SearchStats::default()
+    };
+    let start = Instant::now();
+    let result = search(&mut stats);
+    stats.elapsed = start.elapsed();
+    COLLECTED.with(|collected| {
+        if let Some(collected) = collected.borrow_mut().as_mut() {
+            collected.add(&stats);
+        }
+    });
+    result
+}
+
+/// Whether statistics are being collected on the current thread.
+pub(crate) fn collecting() -> bool {
+    COLLECTED.with(|collected| collected.borrow().is_some())
+}
+
+/// Whether there is a trace hook on the current thread.
+pub(crate) fn tracing() -> bool {
+    TRACE_HOOK.with(|trace_hook| trace_hook.borrow().is_some())
+}
+
+/// Whether searches on the current thread need to count and trace what they do. Doing so makes
+/// each state measurably slower to search, so searches skip it when nobody is looking.
+pub(crate) fn instrumented() -> bool {
+    collecting() || tracing()
+}
+
+/// Pass a state that is about to be expanded to the trace hook, if any.
+pub(crate) fn trace<S: Debug>(stats: &SearchStats, state: &S) {
+    TRACE_HOOK.with(|trace_hook| {
+        if let Some(hook) = trace_hook.borrow_mut().as_mut() {
+            hook(Trace::Expanded(stats, state));
+        }
+    });
+}
+
+/// Pass the plan that a day chose to the trace hook, if any.
+pub(crate) fn trace_solution<S: Debug>(solution: &S) {
+    TRACE_HOOK.with(|trace_hook| {
+        if let Some(hook) = trace_hook.borrow_mut().as_mut() {
+            hook(Trace::Solution(solution));
+        }
+    });
+}
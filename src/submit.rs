@@ -0,0 +1,256 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::config::Config;
+use crate::fetch::agent;
+use crate::fetch::http_error;
+use adventofcode_2022::common::day_input_filename;
+use adventofcode_2022::common::read_input;
+use adventofcode_2022::error::Error;
+
+/// The website's response to a submitted answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited(Duration),
+    /// The part was already solved, or part B was submitted before part A.
+    WrongLevel,
+    Unknown(String),
+}
+
+impl Verdict {
+    const NAMES: [(&'static str, Verdict); 5] = [
+        ("CORRECT", Verdict::Correct),
+        ("WRONG", Verdict::Wrong),
+        ("TOO_HIGH", Verdict::TooHigh),
+        ("TOO_LOW", Verdict::TooLow),
+        ("WRONG_LEVEL", Verdict::WrongLevel),
+    ];
+
+    fn name(&self) -> Option<&'static str> {
+        Self::NAMES
+            .iter()
+            .find(|(_, verdict)| verdict == self)
+            .map(|(name, _)| *name)
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::NAMES
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, verdict)| verdict.clone())
+    }
+
+    /// Interpret the HTML page returned after submitting an answer.
+    pub fn parse_response(html: &str) -> Self {
+        let message = article_text(html);
+        if message.contains("That's the right answer") {
+            Self::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("your answer is too high") {
+                Self::TooHigh
+            } else if message.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Wrong
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Self::RateLimited(parse_wait_time(&message).unwrap_or(UNKNOWN_WAIT_TIME))
+        } else if message.contains("You don't seem to be solving the right level") {
+            Self::WrongLevel
+        } else {
+            Self::Unknown(message)
+        }
+    }
+}
+
+/// The text content of the `<article>` element, or of the whole page if there is none.
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map(|(article, _)| article.split_once('>').map(|(_, a)| a).unwrap_or(article))
+        .unwrap_or(html);
+
+    let mut text = String::with_capacity(article.len());
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// How long to wait when the rate limit message has no wait time that we understand. Waiting too
+/// little means the next answer is rejected and the wait starts over.
+const UNKNOWN_WAIT_TIME: Duration = Duration::from_secs(60);
+
+/// Parse the wait time from a message like "You have 1m 23s left to wait."
+fn parse_wait_time(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (time, _) = rest.split_once(" left to wait")?;
+    time.split_whitespace()
+        .try_fold(0u64, |total, part| {
+            let secs = [("h", 3600), ("m", 60), ("s", 1)]
+                .iter()
+                .find_map(|(unit, secs)| {
+                    let n: u64 = part.strip_suffix(unit)?.parse().ok()?;
+                    n.checked_mul(*secs)
+                })?;
+            total.checked_add(secs)
+        })
+        .map(Duration::from_secs)
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "Correct!"),
+            Self::Wrong => write!(f, "Wrong answer"),
+            Self::TooHigh => write!(f, "Wrong answer: too high"),
+            Self::TooLow => write!(f, "Wrong answer: too low"),
+            Self::RateLimited(wait) => {
+                write!(
+                    f,
+                    "Rate limited: wait {} s before trying again",
+                    wait.as_secs()
+                )
+            }
+            Self::WrongLevel => write!(
+                f,
+                "Not the right level: this part is already solved or locked"
+            ),
+            Self::Unknown(message) => write!(f, "Unrecognized response: {message}"),
+        }
+    }
+}
+
+/// Previous answers submitted for one day, stored next to the input file, for example in
+/// `inputs/2022/day01.guesses`. Each line holds a part, a verdict and the answer, separated by tabs.
+pub struct GuessLog {
+    path: PathBuf,
+    guesses: Vec<(String, Verdict, String)>,
+}
+
+impl GuessLog {
+    pub fn path(year: u16, day: u8) -> PathBuf {
+        day_input_filename(year, day).with_extension("guesses")
+    }
+
+    pub fn read(path: &Path) -> Result<Self, Error> {
+        let guesses = if path.exists() {
+            read_input(path)?
+                .lines()
+                .filter_map(|line| {
+                    let mut fields = line.splitn(3, '\t');
+                    let part = fields.next()?;
+                    let verdict = Verdict::from_name(fields.next()?)?;
+                    let answer = fields.next()?;
+                    Some((part.to_string(), verdict, answer.to_string()))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            guesses,
+        })
+    }
+
+    /// Find a reason not to submit `answer`, if a previous guess shows that it is wrong or that
+    /// the part is already solved.
+    pub fn known_verdict(&self, part: &str, answer: &str) -> Option<String> {
+        let number: Option<i64> = answer.parse().ok();
+        self.guesses
+            .iter()
+            .filter(|(p, _, _)| p == part)
+            .find_map(|(_, verdict, guess)| {
+                let bounds = number.zip(guess.parse::<i64>().ok());
+                match verdict {
+                    Verdict::Correct => {
+                        Some(format!("part {part} was already solved with {guess}"))
+                    }
+                    _ if guess == answer => {
+                        Some(format!("{answer} was already submitted: {verdict}"))
+                    }
+                    Verdict::TooHigh if bounds.is_some_and(|(n, g)| n >= g) => {
+                        Some(format!("{guess} was already too high"))
+                    }
+                    Verdict::TooLow if bounds.is_some_and(|(n, g)| n <= g) => {
+                        Some(format!("{guess} was already too low"))
+                    }
+                    _ => None,
+                }
+            })
+    }
+
+    pub fn record(&mut self, part: &str, verdict: Verdict, answer: &str) -> Result<(), Error> {
+        if let Some(name) = verdict.name() {
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
+            writeln!(file, "{part}\t{name}\t{answer}")?;
+            self.guesses
+                .push((part.to_string(), verdict, answer.to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// Post `answer` for part `part` ("a" or "b") of `day` of `year`.
+pub fn submit_answer(
+    config: &Config,
+    year: u16,
+    day: u8,
+    part: &str,
+    answer: &str,
+) -> Result<Verdict, Error> {
+    let session = config.session()?;
+    let level = if part == "a" { "1" } else { "2" };
+    let url = format!("{}/{year}/day/{day}/answer", config.base_url);
+    let html = agent()
+        .post(&url)
+        .set("Cookie", &format!("session={session}"))
+        .send_form(&[("level", level), ("answer", answer)])
+        .map_err(|err| http_error(&url, err))?
+        .into_string()?;
+    Ok(Verdict::parse_response(&html))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_wait_time() {
+        let wait = |time| parse_wait_time(&format!("You have {time} left to wait."));
+        assert_eq!(wait("1m 23s"), Some(Duration::from_secs(83)));
+        assert_eq!(wait("2h 5s"), Some(Duration::from_secs(7205)));
+        assert_eq!(wait("1m 23√"), None);
+        assert_eq!(wait("√"), None);
+        assert_eq!(wait("m"), None);
+        assert_eq!(wait("99999999999999999h"), None);
+        assert_eq!(wait("5000000000000000h 5000000000000000h"), None);
+        assert_eq!(parse_wait_time("You gave an answer too recently"), None);
+    }
+
+    #[test]
+    fn rate_limit_without_wait_time() {
+        assert_eq!(
+            Verdict::parse_response("<article>You gave an answer too recently.</article>"),
+            Verdict::RateLimited(Duration::from_secs(60))
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use std::time::Duration;
+use std::time::Instant;
+
+/// Wall time spent in each phase of running one day.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DayTiming {
+    pub read: Duration,
+    pub parse: Duration,
+    pub part_a: Option<Duration>,
+    pub part_b: Option<Duration>,
+}
+
+impl DayTiming {
+    pub fn total(&self) -> Duration {
+        self.read + self.parse + self.part_a.unwrap_or_default() + self.part_b.unwrap_or_default()
+    }
+}
+
+/// Run `f` and return its result along with how long it took.
+pub fn timed<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Run `f` and return its result along with the CPU time it took on this thread, if the platform
+/// can measure it.
+pub fn cpu_timed<T, F: FnOnce() -> T>(f: F) -> (T, Option<Duration>) {
+    let start = thread_cpu_time();
+    let result = f();
+    let elapsed = start
+        .zip(thread_cpu_time())
+        .map(|(start, end)| end.saturating_sub(start));
+    (result, elapsed)
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn thread_cpu_time() -> Option<Duration> {
+    use std::os::raw::c_int;
+    use std::os::raw::c_long;
+
+    #[repr(C)]
+    struct Timespec {
+        tv_sec: c_long,
+        tv_nsec: c_long,
+    }
+
+    extern "C" {
+        fn clock_gettime(clock_id: c_int, tp: *mut Timespec) -> c_int;
+    }
+
+    #[cfg(target_os = "linux")]
+    const CLOCK_THREAD_CPUTIME_ID: c_int = 3;
+    #[cfg(target_os = "macos")]
+    const CLOCK_THREAD_CPUTIME_ID: c_int = 16;
+
+    let mut time = Timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: `time` is a valid `struct timespec` for the call to write to.
+    if unsafe { clock_gettime(CLOCK_THREAD_CPUTIME_ID, &mut time) } != 0 {
+        return None;
+    }
+    Some(Duration::new(
+        time.tv_sec.try_into().ok()?,
+        time.tv_nsec.try_into().ok()?,
+    ))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn thread_cpu_time() -> Option<Duration> {
+    None
+}
+
+pub fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.3} ms", millis(duration))
+}
+
+pub fn format_optional_duration(duration: Option<Duration>) -> String {
+    duration
+        .map(format_duration)
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// The `percent`th percentile of `times` by the nearest-rank method, so that the 50th is the
+/// median and the 100th the slowest time.
+pub fn percentile(times: &[Duration], percent: usize) -> Duration {
+    let mut sorted = times.to_vec();
+    sorted.sort_unstable();
+    let rank = (percent * sorted.len()).div_ceil(100).max(1);
+    sorted.get(rank - 1).copied().unwrap_or_default()
+}
@@ -36,6 +36,7 @@ where
 
 impl<I> WithSliding for I where I: Iterator {}
 
+#[allow(unused)]
 pub trait Countable<A> {
     fn counts(self) -> HashMap<A, usize>;
 }
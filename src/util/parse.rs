@@ -0,0 +1,225 @@
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::error::Error;
+use crate::error::ParseError;
+
+/// A cursor over one line of puzzle input, which reports errors at the position where they
+/// occurred.
+#[derive(Clone)]
+pub struct Line<'a> {
+    index: usize,
+    line: &'a str,
+    pos: usize,
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| Line::new(index, line))
+}
+
+pub fn nonempty_lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    self::lines(input).filter(|line| !line.is_empty())
+}
+
+pub fn first_line(input: &str) -> Result<Line<'_>, ParseError> {
+    self::lines(input)
+        .next()
+        .ok_or_else(|| end_of_input(input, "input"))
+}
+
+/// An error for when the input ends before `expected` was found.
+pub fn end_of_input<S: ToString>(input: &str, expected: S) -> ParseError {
+    ParseError::new(input.lines().count() + 1, 1, expected, None)
+}
+
+/// Skip a UTF-8 byte order mark at the start of `input`, if there is one.
+pub fn skip_byte_order_mark(input: &mut dyn BufRead) -> Result<(), Error> {
+    const BOM: &[u8] = "\u{feff}".as_bytes();
+    if input.fill_buf()?.starts_with(BOM) {
+        input.consume(BOM.len());
+    }
+    Ok(())
+}
+
+/// Call `f` with each line read from `input`. Only one line is kept in memory at a time, so this
+/// works for inputs of any size. A byte order mark, CRLF line endings and trailing whitespace are
+/// ignored, as [crate::common::normalize_input] would remove them.
+pub fn for_each_line<F>(input: &mut dyn BufRead, mut f: F) -> Result<(), Error>
+where
+    F: FnMut(Line) -> Result<(), ParseError>,
+{
+    skip_byte_order_mark(input)?;
+    let mut buf = String::new();
+    for index in 0.. {
+        buf.clear();
+        if input.read_line(&mut buf)? == 0 {
+            break;
+        }
+        let line = buf.strip_suffix('\n').unwrap_or(&buf);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        f(Line::new(index, line.trim_end_matches([' ', '\t'])))?;
+    }
+    Ok(())
+}
+
+/// Parse a non-empty rectangular grid where `parse_cell` consumes one cell at a time from each line.
+pub fn grid<T, F>(input: &str, mut parse_cell: F) -> Result<Vec<Vec<T>>, ParseError>
+where
+    F: FnMut(&mut Line, (usize, usize)) -> Result<T, ParseError>,
+{
+    let mut grid: Vec<Vec<T>> = Vec::new();
+    for mut line in nonempty_lines(input) {
+        let w = grid.first().map(Vec::len);
+        let mut row = Vec::with_capacity(line.rest().len());
+        while !line.is_empty() && Some(row.len()) != w {
+            row.push(parse_cell(&mut line, (grid.len(), row.len()))?);
+        }
+        line.expect_end()?;
+        if let Some(w) = w.filter(|w| row.len() < *w) {
+            return Err(line.error(format!("{} more cells", w - row.len())));
+        }
+        grid.push(row);
+    }
+    if grid.is_empty() {
+        return Err(end_of_input(input, "a row of the grid"));
+    }
+    Ok(grid)
+}
+
+impl<'a> Line<'a> {
+    pub fn new(index: usize, line: &'a str) -> Self {
+        Self {
+            index,
+            line,
+            pos: 0,
+        }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.pos..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.rest().starts_with(prefix)
+    }
+
+    pub fn error<S: ToString>(&self, expected: S) -> ParseError {
+        self.error_at(self.pos, expected)
+    }
+
+    fn error_at<S: ToString>(&self, pos: usize, expected: S) -> ParseError {
+        let found = self.line[pos..].split(' ').next().filter(|s| !s.is_empty());
+        ParseError::new(
+            self.index + 1,
+            self.line[..pos].chars().count() + 1,
+            expected,
+            found,
+        )
+    }
+
+    /// Consume `literal` if the rest of the line starts with it.
+    pub fn accept(&mut self, literal: &str) -> bool {
+        if self.starts_with(literal) {
+            self.pos += literal.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn expect(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.accept(literal) {
+            Ok(())
+        } else {
+            Err(self.error(format!("{literal:?}")))
+        }
+    }
+
+    pub fn expect_end(&self) -> Result<(), ParseError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+
+    pub fn char(&mut self) -> Result<char, ParseError> {
+        let c = self
+            .rest()
+            .chars()
+            .next()
+            .ok_or_else(|| self.error("character"))?;
+        self.pos += c.len_utf8();
+        Ok(c)
+    }
+
+    /// Consume one of the characters in `options` and return its index in `options`.
+    pub fn one_of(&mut self, options: &str) -> Result<usize, ParseError> {
+        let expected = || format!("one of {options:?}");
+        let c = self
+            .rest()
+            .chars()
+            .next()
+            .ok_or_else(|| self.error(expected()))?;
+        let i = options
+            .chars()
+            .position(|o| o == c)
+            .ok_or_else(|| self.error(expected()))?;
+        self.pos += c.len_utf8();
+        Ok(i)
+    }
+
+    /// Consume the text up to the next `delimiter`, and the delimiter itself.
+    pub fn until(&mut self, delimiter: &str) -> Result<&'a str, ParseError> {
+        let len = self
+            .rest()
+            .find(delimiter)
+            .ok_or_else(|| self.error_at(self.line.len(), format!("{delimiter:?}")))?;
+        let token = &self.rest()[..len];
+        self.pos += len + delimiter.len();
+        Ok(token)
+    }
+
+    /// Consume the text up to the next `delimiter` or the end of the line, and the delimiter if
+    /// present.
+    pub fn until_or_end(&mut self, delimiter: &str) -> &'a str {
+        let rest = self.rest();
+        match rest.find(delimiter) {
+            Some(len) => {
+                self.pos += len + delimiter.len();
+                &rest[..len]
+            }
+            None => {
+                self.pos = self.line.len();
+                rest
+            }
+        }
+    }
+
+    /// Consume an optionally signed decimal integer.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let rest = self.rest();
+        let sign_len = usize::from(rest.starts_with(['-', '+']));
+        let len = sign_len
+            + rest[sign_len..]
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len() - sign_len);
+        let n = rest[..len].parse().map_err(|_| self.error("integer"))?;
+        self.pos += len;
+        Ok(n)
+    }
+
+    /// Parse the whole rest of the line with [FromStr].
+    pub fn parse_rest<T: FromStr>(&mut self, expected: &str) -> Result<T, ParseError> {
+        let n = self.rest().parse().map_err(|_| self.error(expected))?;
+        self.pos = self.line.len();
+        Ok(n)
+    }
+}
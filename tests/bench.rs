@@ -0,0 +1,70 @@
+mod common;
+
+use std::path::Path;
+use std::process::Output;
+use std::time::Duration;
+
+use adventofcode_2022::baseline::Baseline;
+use adventofcode_2022::baseline::DayBench;
+use common::WorkDir;
+
+fn bench(dir: &WorkDir, args: &[&str]) -> Output {
+    dir.command()
+        .args(["bench", "1", "--year", "2022", "--iterations", "3"])
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn write_baseline(path: &Path, median: Duration) {
+    Baseline {
+        year: 2022,
+        iterations: 3,
+        days: vec![DayBench {
+            day: 1,
+            median,
+            p95: median,
+        }],
+    }
+    .write(path)
+    .unwrap();
+}
+
+#[test]
+fn bench_saves_baseline() {
+    let dir = WorkDir::with_day01_example("bench-save");
+    let output = bench(&dir, &["--save", "baseline.txt"]);
+    assert!(output.status.success(), "{output:?}");
+    let saved = Baseline::read(&dir.path().join("baseline.txt"), 2022).unwrap();
+    assert_eq!(saved.iterations, 3);
+    assert_eq!(
+        saved.days.iter().map(|day| day.day).collect::<Vec<_>>(),
+        vec![1]
+    );
+
+    let output = bench(
+        &dir,
+        &["--baseline", "baseline.txt", "--threshold", "1000000"],
+    );
+    assert!(output.status.success(), "{output:?}");
+}
+
+#[test]
+fn bench_fails_on_regression() {
+    let dir = WorkDir::with_day01_example("bench-regression");
+    write_baseline(&dir.path().join("slow.txt"), Duration::from_secs(1));
+    write_baseline(&dir.path().join("fast.txt"), Duration::from_nanos(1));
+
+    let output = bench(&dir, &["--baseline", "slow.txt"]);
+    assert!(output.status.success(), "{output:?}");
+
+    let output = bench(&dir, &["--baseline", "fast.txt"]);
+    assert!(!output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("REGRESSION"), "{stdout}");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("Slower than the baseline by more than 10%: day 1"),
+        "{stderr}"
+    );
+}
@@ -0,0 +1,105 @@
+// Each test crate includes this module and uses only some of it.
+#![allow(dead_code)]
+
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpListener;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::mpsc;
+
+pub const DAY01_EXAMPLE: &str = include_str!("../../src/days/2022/examples/day01.in");
+
+/// A scratch directory to run the binary in, with an empty `inputs/2022`, removed when dropped.
+pub struct WorkDir(PathBuf);
+
+impl WorkDir {
+    pub fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("aoc-test-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("inputs").join("2022")).unwrap();
+        Self(dir)
+    }
+
+    /// A work directory with the example of day 1 as its input for 2022 day 1.
+    pub fn with_day01_example(name: &str) -> Self {
+        let dir = Self::new(name);
+        std::fs::write(dir.inputs(2022).join("day01.in"), DAY01_EXAMPLE).unwrap();
+        dir
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+
+    pub fn inputs(&self, year: u16) -> PathBuf {
+        self.0.join("inputs").join(year.to_string())
+    }
+
+    /// A command to run the binary in this directory, with its config directory and session
+    /// cookie kept away from the user's.
+    pub fn command(&self) -> Command {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_adventofcode-2022"));
+        command
+            .current_dir(&self.0)
+            .env("XDG_CONFIG_HOME", &self.0)
+            .env("AOC_SESSION", "test-session");
+        command
+    }
+}
+
+impl Drop for WorkDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// A stand-in for the Advent of Code website that answers each request with the next of
+/// `responses`, as a status code and body, and reports the request line and body of each request.
+pub fn stub_server(
+    responses: Vec<(u16, &'static str)>,
+) -> (String, mpsc::Receiver<(String, String)>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let (requests_tx, requests_rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        for (stream, (status, response)) in listener.incoming().zip(responses) {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(&mut stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            requests_tx
+                .send((
+                    request_line.trim().to_string(),
+                    String::from_utf8(body).unwrap(),
+                ))
+                .unwrap();
+            write!(
+                stream,
+                "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                response.len(),
+                response
+            )
+            .unwrap();
+        }
+    });
+    (base_url, requests_rx)
+}
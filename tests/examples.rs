@@ -0,0 +1,90 @@
+use adventofcode_2022::common::Params;
+use adventofcode_2022::days;
+use adventofcode_2022::error::Error;
+
+/// Check each day against the example inputs and answers from its puzzle description. These use
+/// only files in this repository, unlike the tests in `test.rs`.
+fn test_examples(year: u16, day: u8) -> Result<(), Error> {
+    let solver = days::get_solver(year, day).unwrap();
+    let in_range = |name: &str, value: i64| {
+        solver
+            .params()
+            .iter()
+            .any(|param| param.name == name && (param.min..=param.max).contains(&value))
+    };
+    for param in solver.params() {
+        assert!(
+            in_range(param.name, param.default),
+            "Default of {} out of range for {} day {}",
+            param.name,
+            year,
+            day
+        );
+    }
+    for (i, example) in solver.examples().iter().enumerate() {
+        let mut params = Params::new(solver.params());
+        for (name, value) in example.params {
+            assert!(
+                in_range(name, *value),
+                "Parameter {} out of range for {} day {}, example {}",
+                name,
+                year,
+                day,
+                i + 1
+            );
+            params.set(name, *value);
+        }
+        if let Some(solution) = solver.solve_stream(&mut example.input.as_bytes(), &params) {
+            let input = solver.parse_with_params(example.input, params.clone())?;
+            assert_eq!(
+                solution?,
+                (input.part_a(), input.part_b()),
+                "Streaming solution differs for {} day {}, example {}",
+                year,
+                day,
+                i + 1
+            );
+        }
+        let input = solver
+            .parse_with_params(example.input, params)
+            .map_err(|err| err.in_day(day))?;
+        input.check_params()?;
+        if let Some(answer_a) = example.answer_a {
+            assert_eq!(
+                input.part_a(),
+                answer_a,
+                "Incorrect solution for {} day {}a, example {}",
+                year,
+                day,
+                i + 1
+            );
+        }
+        if let Some(answer_b) = example.answer_b {
+            assert_eq!(
+                input.part_b(),
+                answer_b,
+                "Incorrect solution for {} day {}b, example {}",
+                year,
+                day,
+                i + 1
+            );
+        }
+    }
+    Ok(())
+}
+
+macro_rules! test_examples {
+    ($($name: ident),*) => {
+        $(
+            #[test]
+            fn $name() -> Result<(), Error> {
+                let name = stringify!($name);
+                let year: u16 = name[1..5].parse().unwrap();
+                let day_num: u8 = name[9..].parse().unwrap();
+                test_examples(year, day_num)
+            }
+        )*
+    };
+}
+
+adventofcode_2022::for_each_day!(test_examples);
@@ -0,0 +1,82 @@
+mod common;
+
+use std::process::Output;
+
+use common::stub_server;
+use common::WorkDir;
+
+fn fetch(dir: &WorkDir, base_url: &str, year: &str) -> Output {
+    dir.command()
+        .args(["fetch", "1", "--year", year, "--base-url", base_url])
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn fetch_downloads_input_only_once() {
+    let dir = WorkDir::new("fetch-cached");
+    let (base_url, requests) = stub_server(vec![(200, "1000\n2000\n")]);
+
+    let output = fetch(&dir, &base_url, "2022");
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Day  1: downloaded\n"
+    );
+    assert_eq!(
+        requests.recv().unwrap().0,
+        "GET /2022/day/1/input HTTP/1.1".to_string()
+    );
+    assert_eq!(
+        std::fs::read_to_string(dir.inputs(2022).join("day01.in")).unwrap(),
+        "1000\n2000\n"
+    );
+
+    let output = fetch(&dir, &base_url, "2022");
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Day  1: already downloaded\n"
+    );
+    assert!(requests.try_recv().is_err());
+}
+
+#[test]
+fn fetch_reports_error_status_without_saving() {
+    let dir = WorkDir::new("fetch-error");
+    let (base_url, requests) = stub_server(vec![(
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+    )]);
+
+    let output = fetch(&dir, &base_url, "2022");
+    assert!(!output.status.success(), "{output:?}");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("HTTP status 400: Puzzle inputs differ by user."),
+        "{stderr}"
+    );
+    assert!(requests.recv().is_ok());
+    assert_eq!(std::fs::read_dir(dir.inputs(2022)).unwrap().count(), 0);
+}
+
+#[test]
+fn fetch_accepts_years_without_solutions() {
+    let dir = WorkDir::new("fetch-year");
+    let (base_url, requests) = stub_server(vec![(200, "(())\n")]);
+
+    let output = fetch(&dir, &base_url, "2015");
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        requests.recv().unwrap().0,
+        "GET /2015/day/1/input HTTP/1.1".to_string()
+    );
+    assert_eq!(
+        std::fs::read_to_string(dir.inputs(2015).join("day01.in")).unwrap(),
+        "(())\n"
+    );
+
+    let output = fetch(&dir, &base_url, "2014");
+    assert!(!output.status.success(), "{output:?}");
+    assert!(requests.try_recv().is_err());
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use common::stub_server;
+use common::WorkDir;
+
+fn submit(dir: &WorkDir, base_url: &str, part: &str) -> String {
+    let output = dir
+        .command()
+        .args([
+            "submit",
+            "1",
+            part,
+            "--year",
+            "2022",
+            "--base-url",
+            base_url,
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn submit_logs_wrong_answers_and_never_resends_them() {
+    let dir = WorkDir::with_day01_example("submit-wrong");
+    let (base_url, requests) = stub_server(vec![
+        (200, "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.</p></article></main>"),
+    ]);
+
+    let stdout = submit(&dir, &base_url, "a");
+    assert!(stdout.contains("Wrong answer: too high"), "{stdout}");
+    assert_eq!(
+        requests.recv().unwrap(),
+        (
+            "POST /2022/day/1/answer HTTP/1.1".to_string(),
+            "level=1&answer=24000".to_string()
+        )
+    );
+    assert_eq!(
+        std::fs::read_to_string(dir.inputs(2022).join("day01.guesses")).unwrap(),
+        "a\tTOO_HIGH\t24000\n"
+    );
+
+    let stdout = submit(&dir, &base_url, "a");
+    assert!(stdout.contains("Not submitting 24000"), "{stdout}");
+    assert!(requests.try_recv().is_err());
+}
+
+#[test]
+fn submit_reports_rate_limit_without_logging() {
+    let dir = WorkDir::with_day01_example("submit-rate-limit");
+    let (base_url, requests) = stub_server(vec![
+        (200, "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. [<a href=\"/2022/day/1\">Return to Day 1</a>]</p></article></main>"),
+        (200, "<main><article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to collecting enough star fruit.</p></article></main>"),
+    ]);
+
+    let stdout = submit(&dir, &base_url, "b");
+    assert!(stdout.contains("wait 83 s"), "{stdout}");
+    assert_eq!(
+        requests.recv().unwrap().1,
+        "level=2&answer=45000".to_string()
+    );
+    assert!(!dir.inputs(2022).join("day01.guesses").exists());
+
+    let stdout = submit(&dir, &base_url, "b");
+    assert!(stdout.contains("Correct!"), "{stdout}");
+    assert_eq!(
+        std::fs::read_to_string(dir.inputs(2022).join("day01.guesses")).unwrap(),
+        "b\tCORRECT\t45000\n"
+    );
+}
@@ -1,70 +1,234 @@
+use std::time::Duration;
+
+use adventofcode_2022::answers::day_answers_filename;
+use adventofcode_2022::answers::Answers;
+use adventofcode_2022::baseline::Baseline;
+use adventofcode_2022::baseline::DayBench;
 use adventofcode_2022::common::day_input_filename;
-use adventofcode_2022::common::get_file_lines;
+use adventofcode_2022::common::normalize_input;
+use adventofcode_2022::common::read_input;
+use adventofcode_2022::common::Normalization;
+use adventofcode_2022::common::Params;
 use adventofcode_2022::days;
+use adventofcode_2022::error::Error;
+use adventofcode_2022::error::ParseError;
 
-fn test_day(day: u8, correct_a: &str, correct_b: &str) -> Result<(), std::io::Error> {
-    let solve = days::get_solver(day).unwrap();
-    let input_lines = get_file_lines(&day_input_filename(day))?;
-    let (solution_a, solution_b) = solve(&input_lines);
+fn test_day(year: u16, day: u8) -> Result<(), Error> {
+    let solver = days::get_solver(year, day).unwrap();
+    let input_path = day_input_filename(year, day);
+    let answers = Answers::read_for_day(year, day, &input_path)?.unwrap_or_else(|| {
+        panic!(
+            "Answers file not found: {}",
+            day_answers_filename(year, day).display()
+        )
+    });
+    let input = read_input(&input_path)?;
+    let (input, _) = normalize_input(&input);
+    let (solution_a, solution_b) = solver.solve(&input)?;
     assert_eq!(
-        solution_a.as_str(),
-        correct_a,
-        "Incorrect solution for day {}a",
+        Some(solution_a),
+        answers.a,
+        "Incorrect solution for {} day {}a",
+        year,
         day
     );
     assert_eq!(
-        solution_b.as_str(),
-        correct_b,
-        "Incorrect solution for day {}b",
+        Some(solution_b),
+        answers.b,
+        "Incorrect solution for {} day {}b",
+        year,
         day
     );
 
     Ok(())
 }
 
-macro_rules! test_day {
-    ($name: ident, $sol_a: literal, $sol_b: literal) => {
-        #[test]
-        fn $name() -> Result<(), std::io::Error> {
-            let day_name = stringify!($name);
-            let day_num: u8 = day_name[3..].parse().unwrap();
-            test_day(day_num, $sol_a, $sol_b)
+macro_rules! test_days {
+    ($($name: ident),*) => {
+        $(
+            #[test]
+            fn $name() -> Result<(), Error> {
+                let name = stringify!($name);
+                let year: u16 = name[1..5].parse().unwrap();
+                let day_num: u8 = name[9..].parse().unwrap();
+                test_day(year, day_num)
+            }
+        )*
+    };
+}
+
+adventofcode_2022::for_each_day!(test_days);
+
+fn parse_error(day: u8, input: &str) -> ParseError {
+    let result = days::get_solver(2022, day).unwrap().parse(input);
+    result
+        .err()
+        .unwrap_or_else(|| panic!("Expected a parse error for day {day}"))
+}
+
+#[test]
+fn parse_error_position() {
+    assert_eq!(
+        parse_error(4, "2-4,6-8\n2-3,x-5\n"),
+        ParseError::new(2, 5, "integer", Some("x-5"))
+    );
+    assert_eq!(
+        parse_error(9, "R 4\nU\n"),
+        ParseError::new(2, 2, "\" \"", None)
+    );
+    assert_eq!(
+        parse_error(22, "..\n\n1\n"),
+        ParseError::new(1, 1, "net of a cube", Some(".."))
+    );
+    assert_eq!(
+        parse_error(25, "1=-0\n12a\n"),
+        ParseError::new(2, 3, "one of \"=-012\"", Some("a"))
+    );
+}
+
+#[test]
+fn parse_error_for_incomplete_input() {
+    for day in [1, 7, 8, 11, 16, 20, 21, 23] {
+        assert_eq!(parse_error(day, "").line, 1, "day {day}");
+    }
+    assert_eq!(
+        parse_error(6, "abcabc\n"),
+        ParseError::new(1, 7, "a marker of 14 different characters", None)
+    );
+    assert_eq!(
+        parse_error(11, "Monkey 0:\n  Test: divisible by 0\n"),
+        ParseError::new(2, 22, "nonzero divisor", Some("0"))
+    );
+    assert_eq!(
+        parse_error(21, "root: abcd + humn\nhumn: 5\n"),
+        ParseError::new(1, 7, "name of a monkey", Some("abcd"))
+    );
+}
+
+#[test]
+fn stream_parse_error() {
+    for (day, input) in [(1, ""), (1, "1\n\n2\n"), (6, "aaaaaaaaaaaaaaaa\n")] {
+        let solver = days::get_solver(2022, day).unwrap();
+        let params = Params::new(solver.params());
+        match solver.solve_stream(&mut input.as_bytes(), &params) {
+            Some(Err(Error::Parse(err))) => assert_eq!(err, parse_error(day, input)),
+            other => panic!("Expected a parse error for day {day}, got {other:?}"),
         }
+    }
+}
+
+#[test]
+fn parse_error_message() {
+    assert_eq!(
+        parse_error(2, "A Y\nB Q\n").in_day(2).to_string(),
+        r#"day 2, line 2, column 3: expected one of "XYZ", found "Q""#
+    );
+}
+
+#[test]
+fn answers_file_format() {
+    assert_eq!(
+        Answers::parse("A: 13140\nB:\n##..\n###.\n\n"),
+        Ok(Answers {
+            a: Some("13140".to_string()),
+            b: Some("\n##..\n###.".to_string()),
+        })
+    );
+
+    assert_eq!(
+        Answers::parse("B: 4\nA: 5\nB: 6\n"),
+        Err(ParseError::new(3, 1, "each part only once", Some("B:")))
+    );
+}
+
+#[test]
+fn baseline_file_format() {
+    let baseline = Baseline {
+        year: 2022,
+        iterations: 10,
+        days: vec![DayBench {
+            day: 25,
+            median: Duration::from_micros(1500),
+            p95: Duration::from_millis(2),
+        }],
     };
+    let text = "year: 2022\niterations: 10\nday 25: median 1.500000 ms, p95 2.000000 ms\n";
+    assert_eq!(baseline.to_string(), text);
+    assert_eq!(Baseline::parse(text), Ok(baseline));
+
+    assert_eq!(
+        Baseline::parse("year: 2022\niterations: 10\nday 256: median 1 ms, p95 2 ms\n"),
+        Err(ParseError::new(3, 5, "integer", Some("256:")))
+    );
+    assert_eq!(
+        Baseline::parse("year: 2022\niterations: 10\nday 1: median -1 ms, p95 2 ms\n"),
+        Err(ParseError::new(3, 15, "time in milliseconds", Some("-1")))
+    );
+    assert_eq!(
+        Baseline::parse("year: 65536\n"),
+        Err(ParseError::new(1, 7, "integer", Some("65536")))
+    );
+    assert_eq!(
+        Baseline::parse("year: 2022\n"),
+        Err(ParseError::new(2, 1, "\"iterations: \"", None))
+    );
+}
+
+#[test]
+fn day_selection() -> Result<(), Error> {
+    assert_eq!(
+        days::parse_selection(2022, "1,3,7-12")?,
+        vec![1, 3, 7, 8, 9, 10, 11, 12]
+    );
+    assert_eq!(days::parse_selection(2022, "5,2-3,3")?, vec![2, 3, 5]);
+    assert_eq!(
+        days::parse_selection_from(&[2, 3, 5], "1-4,5")?,
+        vec![2, 3, 5]
+    );
+    assert!(matches!(
+        days::parse_selection_from(&[2, 3, 5], "1"),
+        Err(Error::UnknownDay(1, _))
+    ));
+    assert!(matches!(
+        days::parse_selection(2022, "20-26"),
+        Err(Error::UnknownDay(26, _))
+    ));
+    assert!(matches!(
+        days::parse_selection(2022, "3,26"),
+        Err(Error::UnknownDay(26, _))
+    ));
+    assert!(matches!(
+        days::parse_selection(2022, "12-7"),
+        Err(Error::InvalidDay(_))
+    ));
+    assert!(matches!(
+        days::parse_selection(2022, "1,,2"),
+        Err(Error::InvalidDay(_))
+    ));
+    assert_eq!(days::format_selection(&[1, 3, 7, 8, 9, 12]), "1,3,7-9,12");
+    assert_eq!(days::parse_year("2022")?, 2022);
+    assert!(matches!(
+        days::parse_year("2014"),
+        Err(Error::UnknownYear(2014, _))
+    ));
+    assert!(matches!(days::parse_year("x"), Err(Error::InvalidYear(_))));
+    Ok(())
 }
 
-test_day!(day01, "70296", "205381");
-test_day!(day02, "11841", "13022");
-test_day!(day03, "7785", "2633");
-test_day!(day04, "507", "897");
-test_day!(day05, "JRVNHHCSJ", "GNFBSBJLH");
-test_day!(day06, "1804", "2508");
-test_day!(day07, "1886043", "3842121");
-test_day!(day08, "1827", "335580");
-test_day!(day09, "5902", "2445");
-test_day!(
-    day10,
-    "11220",
-    "
-###..####.###...##....##.####.#....#..#.
-#..#....#.#..#.#..#....#.#....#....#.#..
-###....#..#..#.#..#....#.###..#....##...
-#..#..#...###..####....#.#....#....#.#..
-#..#.#....#....#..#.#..#.#....#....#.#..
-###..####.#....#..#..##..####.####.#..#."
-);
-test_day!(day11, "182293", "54832778815");
-test_day!(day12, "394", "388");
-test_day!(day13, "5625", "23111");
-test_day!(day14, "618", "26358");
-test_day!(day15, "5716881", "10852583132904");
-test_day!(day16, "1896", "2576");
-test_day!(day17, "3197", "1568513119571");
-test_day!(day18, "3396", "2044");
-test_day!(day19, "1144", "19980");
-test_day!(day20, "2275", "4090409331120");
-test_day!(day21, "159591692827554", "3509819803065");
-test_day!(day22, "117054", "162096");
-test_day!(day23, "4241", "1079");
-test_day!(day24, "232", "715");
-test_day!(day25, "20-=0=02=-21=00-02=2", "");
+#[test]
+fn input_normalization() {
+    let clean = "    [D]\n[N] [C]\n";
+    assert_eq!(normalize_input(clean), (clean.into(), vec![]));
+    assert_eq!(
+        normalize_input("\u{feff}    [D]    \r\n[N] [C]    "),
+        (
+            clean.into(),
+            vec![
+                Normalization::ByteOrderMark,
+                Normalization::CrLf(1),
+                Normalization::TrailingWhitespace(2),
+                Normalization::MissingFinalNewline,
+            ]
+        )
+    );
+}